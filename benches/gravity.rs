@@ -0,0 +1,92 @@
+//! Compares the exact gravity computation against the Barnes–Hut approximation.
+//! Run with `cargo bench --bench gravity`.
+#![feature(test)]
+extern crate test;
+
+use bevy::math::Vec2;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xoshiro::Xoshiro256PlusPlus;
+use robot_rumble::core::gravity::{
+    BarnesHutTree, Body, GravityApproximation, GravityConfig, GravityField, exact_acceleration,
+};
+use test::{Bencher, black_box};
+
+fn bodies(count: usize) -> Vec<Body> {
+    let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+    (0..count)
+        .map(|_| Body {
+            position: Vec2::new(
+                rng.random_range(-3000.0..3000.0),
+                rng.random_range(-3000.0..3000.0),
+            ),
+            mass: rng.random_range(10.0..100_000.0),
+        })
+        .collect()
+}
+
+fn bench_exact(b: &mut Bencher, count: usize) {
+    let bodies = bodies(count);
+    let config = GravityConfig::default();
+    b.iter(|| {
+        for body in bodies.iter() {
+            black_box(exact_acceleration(&bodies, body.position, &config));
+        }
+    });
+}
+
+/// Includes the tree construction, as it happens every frame
+fn bench_barnes_hut(b: &mut Bencher, count: usize) {
+    let bodies = bodies(count);
+    let config = GravityConfig::default();
+    let theta = GravityApproximation::default().theta;
+    b.iter(|| {
+        let tree = BarnesHutTree::new(bodies.clone());
+        for body in bodies.iter() {
+            black_box(tree.acceleration(body.position, theta, &config));
+        }
+    });
+}
+
+#[bench]
+fn exact_10(b: &mut Bencher) {
+    bench_exact(b, 10);
+}
+
+#[bench]
+fn exact_100(b: &mut Bencher) {
+    bench_exact(b, 100);
+}
+
+#[bench]
+fn exact_1000(b: &mut Bencher) {
+    bench_exact(b, 1000);
+}
+
+#[bench]
+fn barnes_hut_10(b: &mut Bencher) {
+    bench_barnes_hut(b, 10);
+}
+
+#[bench]
+fn barnes_hut_100(b: &mut Bencher) {
+    bench_barnes_hut(b, 100);
+}
+
+#[bench]
+fn barnes_hut_1000(b: &mut Bencher) {
+    bench_barnes_hut(b, 1000);
+}
+
+/// What `apply_forces` actually does with the default settings
+#[bench]
+fn default_field_1000(b: &mut Bencher) {
+    let bodies = bodies(1000);
+    let config = GravityConfig::default();
+    let approximation = GravityApproximation::default();
+    b.iter(|| {
+        let field = GravityField::new(bodies.clone(), &config, &approximation);
+        for body in bodies.iter() {
+            black_box(field.acceleration(body.position));
+        }
+    });
+}
@@ -3,10 +3,12 @@ use bevy_asset_loader::prelude::*;
 use bevy_common_assets::ron::RonAssetPlugin;
 
 use crate::{
-    core::worldgen,
+    core::{gravity, worldgen},
     entities::{
+        blackhole::config as blackhole,
+        pickup::config as pickup,
         planet,
-        player::{skin as player_skin, weapon},
+        player::{config as player, skin as player_skin, weapon},
         projectile::config as projectiles,
         satellite::assets as satellite,
     },
@@ -18,41 +20,70 @@ use crate::{
 pub struct AssetsPlugin;
 impl Plugin for AssetsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(RonAssetPlugin::<worldgen::WorldgenConfig>::new(&[
-            "worldgen.ron",
-        ]))
-        .add_plugins(RonAssetPlugin::<planet::PlanetsConfig>::new(&[
+        app.add_plugins(RonAssetPlugin::<planet::PlanetsConfig>::new(&[
             "planets.ron",
         ]))
         .init_asset::<player_skin::Skin>()
         .add_plugins(RonAssetPlugin::<player_skin::SkinConfig>::new(&[
             "skin.ron",
         ]))
+        .add_loading_state(
+            add_simulation_collections(
+                LoadingState::new(ui::Screen::AssetLoading).continue_to_state(ui::Screen::Home),
+            )
+            .load_collection::<planet::PlanetAssets>()
+            .load_collection::<player_skin::SkinConfigAssets>()
+            .finally_init_resource::<player_skin::SkinAssets>()
+            .finally_init_resource::<weapon::assets::WeaponsAssets>()
+            .load_collection::<satellite::SatelliteSpriteAssets>()
+            .load_collection::<ui::UIAssets>(),
+        );
+    }
+}
+
+/// Adds the collections the simulation depends on, shared with `simulation::HeadlessPlugin`.
+pub fn add_simulation_collections(state: LoadingState<ui::Screen>) -> LoadingState<ui::Screen> {
+    state
+        .load_collection::<worldgen::WorldgenAssets>()
+        .load_collection::<gravity::GravityAssets>()
+        .load_collection::<player::PlayerAssets>()
+        .load_collection::<weapon::config::WeaponsConfigAssets>()
+        .load_collection::<projectiles::ProjectilesAssets>()
+        .load_collection::<blackhole::BlackHoleAssets>()
+        .load_collection::<pickup::PickupAssets>()
+        .load_collection::<satellite::SatelliteAssets>()
+        .load_collection::<network::NetworkAssets>()
+}
+
+/// Registers the asset loaders of the config files the simulation depends on.
+/// Part of `SimulationPlugins`, as it doesn't require any renderer.
+pub struct ConfigAssetsPlugin;
+impl Plugin for ConfigAssetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(RonAssetPlugin::<worldgen::WorldgenConfig>::new(&[
+            "worldgen.ron",
+        ]))
+        .add_plugins(RonAssetPlugin::<gravity::GravityConfig>::new(&[
+            "gravity.ron",
+        ]))
+        .add_plugins(RonAssetPlugin::<player::PlayerConfig>::new(&["player.ron"]))
         .add_plugins(RonAssetPlugin::<weapon::config::WeaponsConfig>::new(&[
             "weapons.ron",
         ]))
         .add_plugins(RonAssetPlugin::<projectiles::ProjectilesConfig>::new(&[
             "projectiles.ron",
         ]))
+        .add_plugins(RonAssetPlugin::<blackhole::BlackHoleConfig>::new(&[
+            "blackhole.ron",
+        ]))
+        .add_plugins(RonAssetPlugin::<pickup::PickupConfig>::new(&[
+            "pickups.ron",
+        ]))
         .add_plugins(RonAssetPlugin::<satellite::SatelliteConfig>::new(&[
             "satellites.ron",
         ]))
         .add_plugins(RonAssetPlugin::<network::NetworkConfig>::new(&[
             "network.ron",
-        ]))
-        .add_loading_state(
-            LoadingState::new(crate::ui::Screen::AssetLoading)
-                .continue_to_state(crate::ui::Screen::Home)
-                .load_collection::<worldgen::WorldgenAssets>()
-                .load_collection::<planet::PlanetAssets>()
-                .load_collection::<player_skin::SkinConfigAssets>()
-                .finally_init_resource::<player_skin::SkinAssets>()
-                .load_collection::<weapon::config::WeaponsConfigAssets>()
-                .finally_init_resource::<weapon::assets::WeaponsAssets>()
-                .load_collection::<projectiles::ProjectilesAssets>()
-                .load_collection::<satellite::SatelliteAssets>()
-                .load_collection::<network::NetworkAssets>()
-                .load_collection::<ui::UIAssets>(),
-        );
+        ]));
     }
 }
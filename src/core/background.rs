@@ -17,6 +17,7 @@ impl Plugin for BackgroundPlugin {
         app.add_plugins(Material2dPlugin::<NebulaeMaterial>::default())
             .add_plugins(Material2dPlugin::<StarsMaterial>::default())
             .add_systems(OnEnter(GameState::WorldGen), setup)
+            .add_systems(OnExit(GameState::Results), despawn)
             .add_systems(Update, scale_and_center);
     }
 }
@@ -181,3 +182,9 @@ fn scale_and_center(
 
     Ok(())
 }
+
+fn despawn(mut commands: Commands, query: Query<Entity, With<Background>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
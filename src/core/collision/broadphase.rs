@@ -0,0 +1,150 @@
+use std::marker::PhantomData;
+
+use bevy::{math::FloatPow as _, prelude::*};
+
+use super::{CollisionShape, sweep_circle};
+use crate::core::physics::Position;
+
+/// Sweep-and-prune structure holding every entity with component `B`, sorted along the X axis.
+/// Rebuilt each frame before collision checks, so it never needs to be rolled back.
+/// Shared by all `CollisionPlugin<_, B>` instantiations.
+#[derive(Resource)]
+pub struct Broadphase<B> {
+    entries: Vec<Entry>,
+    /// Biggest bounding radius, used to bound the search
+    max_radius: f32,
+    _data: PhantomData<B>,
+}
+
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub entity: Entity,
+    pub position: Vec2,
+    pub radius: f32,
+    /// Query iteration order, used for breaking ties like a linear scan would
+    order: usize,
+}
+
+pub(super) fn update_broadphase<B: Component>(
+    mut broadphase: ResMut<Broadphase<B>>,
+    query: Query<(Entity, &Position, &CollisionShape), With<B>>,
+) {
+    broadphase.entries.clear();
+    broadphase.entries.extend(query.iter().enumerate().map(
+        |(order, (entity, position, shape))| Entry {
+            entity,
+            position: position.0,
+            radius: shape.bounding_radius(),
+            order,
+        },
+    ));
+    broadphase.entries.sort_unstable_by(|a, b| {
+        a.position
+            .x
+            .total_cmp(&b.position.x)
+            .then(a.order.cmp(&b.order))
+    });
+    broadphase.max_radius = broadphase
+        .entries
+        .iter()
+        .map(|entry| entry.radius)
+        .fold(0., f32::max);
+}
+
+impl<B> Broadphase<B> {
+    /// Returns the entry minimizing `distance² - bounding_radius²` from `position`.
+    /// Ties are resolved in query iteration order, which makes it equivalent to a linear `min_by`.
+    /// `exclude` is skipped, so that entities can't collide with themselves when `A` is `B`.
+    pub fn closest(&self, position: Vec2, exclude: Entity) -> Option<&Entry> {
+        let max_radius_squared = self.max_radius.squared();
+        let start = self
+            .entries
+            .partition_point(|entry| entry.position.x < position.x);
+
+        let mut best: Option<(f32, &Entry)> = None;
+        let mut left = start.checked_sub(1);
+        let mut right = (start < self.entries.len()).then_some(start);
+
+        // Sweep outwards, always picking the nearest side along X
+        loop {
+            let left_dx = left.map(|i| position.x - self.entries[i].position.x);
+            let right_dx = right.map(|i| self.entries[i].position.x - position.x);
+
+            let (index, dx, go_left) = match (left_dx, right_dx) {
+                (None, None) => break,
+                (Some(dx), None) => (left.unwrap(), dx, true),
+                (None, Some(dx)) => (right.unwrap(), dx, false),
+                (Some(l), Some(r)) if l <= r => (left.unwrap(), l, true),
+                (Some(_), Some(r)) => (right.unwrap(), r, false),
+            };
+
+            // Entries further along X can't get closer than this lower bound
+            if let Some((best_distance, _)) = best
+                && dx.squared() - max_radius_squared > best_distance
+            {
+                break;
+            }
+
+            if go_left {
+                left = left.and_then(|i| i.checked_sub(1));
+            } else {
+                right = right.map(|i| i + 1).filter(|i| *i < self.entries.len());
+            }
+
+            let entry = &self.entries[index];
+            if entry.entity == exclude {
+                continue;
+            }
+            let distance = entry.position.distance_squared(position) - entry.radius.squared();
+            let is_better = best.is_none_or(|(best_distance, best_entry)| {
+                distance
+                    .total_cmp(&best_distance)
+                    .then(entry.order.cmp(&best_entry.order))
+                    .is_lt()
+            });
+            if is_better {
+                best = Some((distance, entry));
+            }
+        }
+
+        best.map(|(_, entry)| entry)
+    }
+
+    /// Returns the first entry hit by a circle of `radius` moving from `from` to `to`, along with
+    /// its time of impact. Entries whose bounding circle gets hit are narrowed down with
+    /// `time_of_impact`, which tests their actual shape.
+    /// Ties are resolved in query iteration order, and `exclude` is skipped like in `closest`.
+    pub fn first_hit(
+        &self,
+        from: Vec2,
+        to: Vec2,
+        radius: f32,
+        exclude: Entity,
+        time_of_impact: impl Fn(&Entry) -> Option<f32>,
+    ) -> Option<(f32, &Entry)> {
+        let margin = radius + self.max_radius;
+        let min_x = from.x.min(to.x) - margin;
+        let max_x = from.x.max(to.x) + margin;
+        let start = self
+            .entries
+            .partition_point(|entry| entry.position.x < min_x);
+
+        self.entries[start..]
+            .iter()
+            .take_while(|entry| entry.position.x <= max_x)
+            .filter(|entry| entry.entity != exclude)
+            .filter(|entry| sweep_circle(from, to, entry.position, radius + entry.radius).is_some())
+            .filter_map(|entry| time_of_impact(entry).map(|time| (time, entry)))
+            .min_by(|(a_time, a), (b_time, b)| a_time.total_cmp(b_time).then(a.order.cmp(&b.order)))
+    }
+}
+
+impl<B> Default for Broadphase<B> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            max_radius: 0.,
+            _data: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,323 @@
+use std::marker::PhantomData;
+
+use bevy::{
+    ecs::entity::{EntityMapper, MapEntities},
+    math::FloatPow as _,
+    prelude::*,
+    utils::Parallel,
+};
+use bevy_ggrs::GgrsSchedule;
+
+use super::physics::{PhysicsSet, Position, Rotation};
+
+mod broadphase;
+mod raycast;
+mod shape;
+pub use broadphase::Broadphase;
+pub use raycast::{RayHit, ray_cast};
+pub use shape::CollisionShape;
+
+/// Opt-in continuous collision detection.
+/// Collisions get checked along the path travelled during the frame instead of only at its end,
+/// so that fast entities can't tunnel through thin ones.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+pub struct SweptCollision {
+    /// Position before this frame's movement. `None` until the entity went through `PhysicsSet::Movement`.
+    pub start: Option<Vec2>,
+}
+
+#[derive(Component, Clone, Reflect)]
+pub struct CollisionState<A, B> {
+    pub closest: Option<Entity>,
+    pub collides: bool,
+    _data: PhantomData<(A, B)>,
+}
+
+/// Sent when entity `A` starts colliding with entity `B`.
+///
+/// Collision events only hold the transitions of the latest collision pass, and get rewritten on each
+/// resimulated frame. Read them in `GgrsSchedule` after `PhysicsSet::Collision` to stay rollback-safe.
+#[derive(Event)]
+pub struct CollisionStarted<A, B> {
+    pub entity: Entity,
+    pub other: Entity,
+    _data: PhantomData<(A, B)>,
+}
+
+/// Sent when entity `A` stops colliding with entity `B`. See `CollisionStarted` for semantics.
+#[derive(Event)]
+pub struct CollisionEnded<A, B> {
+    pub entity: Entity,
+    pub other: Entity,
+    _data: PhantomData<(A, B)>,
+}
+
+/// Adds systems that checks collisions between entities with component `A`, and entities with component `B`.
+/// Entity `A` gets added the `CollisionState` component to get collisions notified,
+/// and transitions are sent as `CollisionStarted` and `CollisionEnded` events.
+pub struct CollisionPlugin<A, B>(PhantomData<(A, B)>);
+impl<A, B> Plugin for CollisionPlugin<A, B>
+where
+    A: Component,
+    B: Component,
+{
+    fn build(&self, app: &mut App) {
+        // The broadphase of `B` is shared with other `CollisionPlugin<_, B>`
+        if !app.world().contains_resource::<Broadphase<B>>() {
+            app.init_resource::<Broadphase<B>>().add_systems(
+                GgrsSchedule,
+                broadphase::update_broadphase::<B>.in_set(PhysicsSet::Collision),
+            );
+        }
+
+        if !app.is_plugin_added::<SweptCollisionPlugin>() {
+            app.add_plugins(SweptCollisionPlugin);
+        }
+
+        app.register_type::<CollisionShape>()
+            .register_required_components::<A, CollisionState<A, B>>()
+            .add_event::<CollisionStarted<A, B>>()
+            .add_event::<CollisionEnded<A, B>>()
+            .add_systems(
+                GgrsSchedule,
+                check_collisions::<A, B>
+                    .in_set(PhysicsSet::Collision)
+                    .after(broadphase::update_broadphase::<B>),
+            );
+    }
+}
+
+struct SweptCollisionPlugin;
+impl Plugin for SweptCollisionPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<SweptCollision>().add_systems(
+            GgrsSchedule,
+            record_sweep_start
+                .after(PhysicsSet::Interaction)
+                .before(PhysicsSet::Movement),
+        );
+    }
+}
+
+fn record_sweep_start(mut query: Query<(&mut SweptCollision, &Position)>) {
+    for (mut sweep, position) in query.iter_mut() {
+        sweep.start = Some(position.0);
+    }
+}
+
+pub(crate) fn check_collisions<A, B>(
+    mut query_a: Query<
+        (
+            Entity,
+            &mut CollisionState<A, B>,
+            &Position,
+            Option<&Rotation>,
+            &CollisionShape,
+            Option<&SweptCollision>,
+        ),
+        With<A>,
+    >,
+    query_b: Query<(&CollisionShape, Option<&Rotation>), With<B>>,
+    broadphase: Res<Broadphase<B>>,
+    mut transitions: Local<Parallel<Vec<(Vec2, Transition)>>>,
+    mut started_events: ResMut<Events<CollisionStarted<A, B>>>,
+    mut ended_events: ResMut<Events<CollisionEnded<A, B>>>,
+) where
+    A: Component,
+    B: Component,
+{
+    query_a.par_iter_mut().for_each(
+        |(entity, mut a_collision_state, a_position, a_rotation, a_shape, sweep)| {
+            // First hit along the travelled path takes precedence over the end position.
+            // The moving entity is approximated by its bounding circle, the other one keeps its shape.
+            let first_hit = sweep
+                .and_then(|sweep| sweep.start)
+                .filter(|start| *start != a_position.0)
+                .and_then(|start| {
+                    let radius = a_shape.bounding_radius();
+                    broadphase.first_hit(start, a_position.0, radius, entity, |entry| {
+                        let (b_shape, b_rotation) = query_b.get(entry.entity).ok()?;
+                        b_shape.sweep(
+                            isometry(&Position(entry.position), b_rotation),
+                            start,
+                            a_position.0,
+                            radius,
+                        )
+                    })
+                });
+
+            let (closest, collides) = if let Some((_, entry)) = first_hit {
+                (Some(entry.entity), true)
+            } else {
+                broadphase
+                    .closest(a_position.0, entity)
+                    .and_then(|entry| Some((entry, query_b.get(entry.entity).ok()?)))
+                    .map_or_else(
+                        || (None, false),
+                        |(entry, (b_shape, b_rotation))| {
+                            let collides = a_shape.collides_with(
+                                isometry(a_position, a_rotation),
+                                b_shape,
+                                isometry(&Position(entry.position), b_rotation),
+                            );
+                            (Some(entry.entity), collides)
+                        },
+                    )
+            };
+
+            let previous = a_collision_state
+                .collides
+                .then_some(a_collision_state.closest)
+                .flatten();
+            let current = collides.then_some(closest).flatten();
+            if previous != current {
+                let mut transitions = transitions.borrow_local_mut();
+                if let Some(other) = previous {
+                    transitions.push((a_position.0, Transition::Ended(entity, other)));
+                }
+                if let Some(other) = current {
+                    transitions.push((a_position.0, Transition::Started(entity, other)));
+                }
+            }
+
+            if a_collision_state.closest != closest {
+                // Update only if changed in order to properly trigger Changed<C> events
+                a_collision_state.closest = closest;
+            }
+            if a_collision_state.collides != collides {
+                // Same
+                a_collision_state.collides = collides;
+            }
+        },
+    );
+
+    // Threads may have collected transitions in any order
+    let mut transitions = transitions.drain().collect::<Vec<_>>();
+    transitions.sort_by(|(a, a_transition), (b, b_transition)| {
+        a.x.total_cmp(&b.x)
+            .then(a.y.total_cmp(&b.y))
+            .then(a_transition.is_started().cmp(&b_transition.is_started()))
+            .then(a_transition.entities().cmp(&b_transition.entities()))
+    });
+
+    // Events of the previous pass are stale, and must not leak into a resimulated frame
+    started_events.clear();
+    ended_events.clear();
+    for (_, transition) in transitions {
+        match transition {
+            Transition::Started(entity, other) => {
+                started_events.send(CollisionStarted {
+                    entity,
+                    other,
+                    _data: PhantomData,
+                });
+            }
+            Transition::Ended(entity, other) => {
+                ended_events.send(CollisionEnded {
+                    entity,
+                    other,
+                    _data: PhantomData,
+                });
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum Transition {
+    Started(Entity, Entity),
+    Ended(Entity, Entity),
+}
+
+impl Transition {
+    fn is_started(&self) -> bool {
+        matches!(self, Transition::Started(..))
+    }
+
+    fn entities(&self) -> (Entity, Entity) {
+        match self {
+            Transition::Started(entity, other) | Transition::Ended(entity, other) => {
+                (*entity, *other)
+            }
+        }
+    }
+}
+
+impl<A, B> CollisionPlugin<A, B>
+where
+    A: Component,
+    B: Component,
+{
+    pub fn new() -> Self {
+        Self(default())
+    }
+}
+
+impl<A, B> Default for CollisionPlugin<A, B>
+where
+    A: Component,
+    B: Component,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A, B> MapEntities for CollisionState<A, B>
+where
+    A: Component,
+    B: Component,
+{
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.closest.map_entities(entity_mapper);
+    }
+}
+
+impl<A, B> Default for CollisionState<A, B>
+where
+    A: Component,
+    B: Component,
+{
+    fn default() -> Self {
+        Self {
+            closest: None,
+            collides: false,
+            _data: default(),
+        }
+    }
+}
+
+/// World placement of a shape, as expected by `CollisionShape::collides_with`
+pub fn isometry(position: &Position, rotation: Option<&Rotation>) -> Isometry2d {
+    Isometry2d::new(
+        position.0,
+        rotation.map_or(Rot2::IDENTITY, |rotation| Rot2::radians(rotation.0)),
+    )
+}
+
+/// Earliest time of impact in `[0, 1]` of a circle of `radius` moving from `from` to `to`
+/// against a static circle. Only relies on IEEE exact operations, keeping it deterministic.
+pub fn sweep_circle(from: Vec2, to: Vec2, center: Vec2, radius: f32) -> Option<f32> {
+    let offset = from - center;
+    let c = offset.length_squared() - radius.squared();
+    if c <= 0. {
+        // Already overlapping
+        return Some(0.);
+    }
+
+    let direction = to - from;
+    let a = direction.length_squared();
+    let b = offset.dot(direction);
+    if a == 0. || b >= 0. {
+        // Not moving, or moving away
+        return None;
+    }
+
+    let discriminant = b.squared() - a * c;
+    if discriminant < 0. {
+        return None;
+    }
+
+    let t = (-b - discriminant.sqrt()) / a;
+    (t <= 1.).then_some(t)
+}
@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+
+use super::{CollisionShape, isometry};
+use crate::core::physics::{Position, Rotation};
+
+/// First shape hit by a ray
+#[derive(Clone, Copy, Debug)]
+pub struct RayHit {
+    pub entity: Entity,
+    /// Fraction of the ray travelled before hitting, in `[0, 1]`
+    pub time: f32,
+    pub point: Vec2,
+}
+
+/// Casts a ray going from `from` to `to` against `targets`, returning the first hit.
+///
+/// Targets containing `from` are ignored, which lets rays start from the edge of their caster.
+/// Ties are resolved in iteration order, like `Broadphase` does. Only relies on IEEE exact
+/// operations, so it can be used inside the rollback schedule.
+pub fn ray_cast<'a>(
+    from: Vec2,
+    to: Vec2,
+    targets: impl IntoIterator<
+        Item = (
+            Entity,
+            &'a Position,
+            Option<&'a Rotation>,
+            &'a CollisionShape,
+        ),
+    >,
+) -> Option<RayHit> {
+    targets
+        .into_iter()
+        .filter_map(|(entity, position, rotation, shape)| {
+            shape
+                .ray_cast(isometry(position, rotation), from, to)
+                .map(|time| (entity, time))
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(entity, time)| RayHit {
+            entity,
+            time,
+            point: from.lerp(to, time),
+        })
+}
@@ -0,0 +1,395 @@
+use bevy::{math::FloatPow as _, prelude::*};
+
+use super::sweep_circle;
+
+/// Collision shape, expressed in local space around `Position` and rotated by `Rotation`.
+///
+/// Every shape is a convex core (a point, a segment or a polygon) inflated by a radius,
+/// which lets a single exact test handle all pairs.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+pub enum CollisionShape {
+    #[default]
+    Point,
+    /// Uses f32 internally to avoid casts
+    Circle(f32),
+    /// Segment along the local X axis, inflated by `radius`
+    Capsule { half_length: f32, radius: f32 },
+    /// Oriented box
+    Rectangle { half_size: Vec2 },
+    /// Convex polygon, vertices being in winding order
+    Polygon(Vec<Vec2>),
+}
+
+impl CollisionShape {
+    pub fn bounding_radius(&self) -> f32 {
+        match self {
+            CollisionShape::Point => 0.0,
+            CollisionShape::Circle(radius) => *radius,
+            CollisionShape::Capsule {
+                half_length,
+                radius,
+            } => half_length + radius,
+            CollisionShape::Rectangle { half_size } => half_size.length(),
+            CollisionShape::Polygon(vertices) => vertices
+                .iter()
+                .map(|vertex| vertex.length())
+                .fold(0., f32::max),
+        }
+    }
+
+    /// Exact intersection test between two shapes placed in the world
+    pub fn collides_with(
+        &self,
+        self_isometry: Isometry2d,
+        other: &Self,
+        other_isometry: Isometry2d,
+    ) -> bool {
+        let radius_squared = (self.radius() + other.radius()).squared();
+
+        // Rotation has no effect on round shapes, keep the cheap path for them
+        if self.is_round() && other.is_round() {
+            let distance_squared = self_isometry
+                .translation
+                .distance_squared(other_isometry.translation);
+            return distance_squared <= radius_squared;
+        }
+
+        let self_core = self.core(self_isometry);
+        let other_core = other.core(other_isometry);
+
+        core_distance_squared(&self_core, &other_core) <= radius_squared
+    }
+
+    /// Earliest time of impact in `[0, 1]` of a ray going from `from` to `to`.
+    /// Rays starting inside the shape don't hit it.
+    pub fn ray_cast(&self, isometry: Isometry2d, from: Vec2, to: Vec2) -> Option<f32> {
+        // Only overlapping rays hit right away
+        self.cast(isometry, from, to, 0.).filter(|time| *time > 0.)
+    }
+
+    /// Earliest time of impact in `[0, 1]` of a circle of `radius` moving from `from` to `to`.
+    /// Circles starting inside the shape hit it at `0.`.
+    pub fn sweep(&self, isometry: Isometry2d, from: Vec2, to: Vec2, radius: f32) -> Option<f32> {
+        self.cast(isometry, from, to, radius)
+    }
+
+    /// Sweeps a circle of `radius` against the shape, `0.` if they overlap from the start
+    fn cast(&self, isometry: Isometry2d, from: Vec2, to: Vec2, radius: f32) -> Option<f32> {
+        let radius = self.radius() + radius;
+
+        if self.is_round() {
+            return sweep_circle(from, to, isometry.translation, radius);
+        }
+
+        let core = self.core(isometry);
+        if core_distance_squared(&core, &[from]) <= radius.squared() {
+            return Some(0.);
+        }
+
+        // The inflated core is bounded by circles around vertices, and edges pushed along their normals
+        let direction = to - from;
+        let vertex_hits = core
+            .iter()
+            .filter_map(|vertex| sweep_circle(from, to, *vertex, radius));
+        let edge_hits = edges(&core).flat_map(|(start, end)| {
+            let offset = (end - start).perp().normalize_or_zero() * radius;
+            [offset, -offset].into_iter().filter_map(move |offset| {
+                ray_segment_intersection(from, direction, start + offset, end + offset)
+            })
+        });
+
+        vertex_hits.chain(edge_hits).reduce(f32::min)
+    }
+
+    fn is_round(&self) -> bool {
+        matches!(self, CollisionShape::Point | CollisionShape::Circle(_))
+    }
+
+    /// Inflation radius around the core
+    fn radius(&self) -> f32 {
+        match self {
+            CollisionShape::Circle(radius) | CollisionShape::Capsule { radius, .. } => *radius,
+            _ => 0.0,
+        }
+    }
+
+    /// Core vertices in world space
+    fn core(&self, isometry: Isometry2d) -> Vec<Vec2> {
+        let local = match self {
+            CollisionShape::Point | CollisionShape::Circle(_) => vec![Vec2::ZERO],
+            CollisionShape::Capsule { half_length, .. } => {
+                vec![Vec2::new(-half_length, 0.), Vec2::new(*half_length, 0.)]
+            }
+            CollisionShape::Rectangle { half_size } => vec![
+                Vec2::new(-half_size.x, -half_size.y),
+                Vec2::new(half_size.x, -half_size.y),
+                Vec2::new(half_size.x, half_size.y),
+                Vec2::new(-half_size.x, half_size.y),
+            ],
+            CollisionShape::Polygon(vertices) if vertices.is_empty() => vec![Vec2::ZERO],
+            CollisionShape::Polygon(vertices) => vertices.clone(),
+        };
+
+        local
+            .into_iter()
+            .map(|vertex| isometry.transform_point(vertex))
+            .collect()
+    }
+}
+
+/// Squared distance between two convex cores, `0.` if they overlap
+fn core_distance_squared(a: &[Vec2], b: &[Vec2]) -> f32 {
+    let crossing = edges(a).any(|(a1, a2)| edges(b).any(|(b1, b2)| segments_cross(a1, a2, b1, b2)));
+    if crossing || contains(a, b[0]) || contains(b, a[0]) {
+        return 0.;
+    }
+
+    let a_to_b = a.iter().flat_map(|point| {
+        edges(b).map(|(start, end)| point_segment_distance_squared(*point, start, end))
+    });
+    let b_to_a = b.iter().flat_map(|point| {
+        edges(a).map(|(start, end)| point_segment_distance_squared(*point, start, end))
+    });
+
+    a_to_b.chain(b_to_a).fold(f32::INFINITY, f32::min)
+}
+
+/// Edges of a core. Points and segments yield a single, possibly degenerate, edge.
+fn edges(vertices: &[Vec2]) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+    let count = match vertices.len() {
+        0 => 0,
+        1 | 2 => 1,
+        len => len,
+    };
+
+    (0..count).map(move |i| (vertices[i], vertices[(i + 1) % vertices.len()]))
+}
+
+/// Whether `point` lies inside the convex polygon, regardless of its winding order
+fn contains(polygon: &[Vec2], point: Vec2) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+
+    let mut sign = 0.;
+    for (start, end) in edges(polygon) {
+        let cross = (end - start).perp_dot(point - start);
+        if cross * sign < 0. {
+            return false;
+        }
+        if cross != 0. {
+            sign = cross;
+        }
+    }
+
+    true
+}
+
+/// Whether both segments properly cross each other. Touching is handled by distance checks.
+fn segments_cross(a1: Vec2, a2: Vec2, b1: Vec2, b2: Vec2) -> bool {
+    let a = a2 - a1;
+    let b = b2 - b1;
+
+    a.perp_dot(b1 - a1) * a.perp_dot(b2 - a1) < 0. && b.perp_dot(a1 - b1) * b.perp_dot(a2 - b1) < 0.
+}
+
+/// Time in `[0, 1]` at which `origin + time * direction` crosses the segment, `None` if parallel
+fn ray_segment_intersection(origin: Vec2, direction: Vec2, start: Vec2, end: Vec2) -> Option<f32> {
+    let edge = end - start;
+    let denominator = direction.perp_dot(edge);
+    if denominator == 0. {
+        // Parallel hits are caught at the vertices
+        return None;
+    }
+
+    let offset = start - origin;
+    let time = offset.perp_dot(edge) / denominator;
+    let along_edge = offset.perp_dot(direction) / denominator;
+    ((0. ..=1.).contains(&time) && (0. ..=1.).contains(&along_edge)).then_some(time)
+}
+
+fn point_segment_distance_squared(point: Vec2, start: Vec2, end: Vec2) -> f32 {
+    let segment = end - start;
+    let length_squared = segment.length_squared();
+    if length_squared == 0. {
+        return point.distance_squared(start);
+    }
+
+    let t = ((point - start).dot(segment) / length_squared).clamp(0., 1.);
+    point.distance_squared(start + segment * t)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::*;
+
+    const EPSILON: f32 = 1e-4;
+
+    fn at(x: f32, y: f32) -> Isometry2d {
+        Isometry2d::from_translation(Vec2::new(x, y))
+    }
+
+    fn rotated(x: f32, y: f32, angle: f32) -> Isometry2d {
+        Isometry2d::new(Vec2::new(x, y), Rot2::radians(angle))
+    }
+
+    fn bar() -> CollisionShape {
+        CollisionShape::Rectangle {
+            half_size: Vec2::new(10., 1.),
+        }
+    }
+
+    fn triangle() -> CollisionShape {
+        CollisionShape::Polygon(vec![Vec2::ZERO, Vec2::new(0., 4.), Vec2::new(4., 0.)])
+    }
+
+    #[test]
+    fn test_point_segment_distance() {
+        let (start, end) = (Vec2::new(-1., 0.), Vec2::new(1., 0.));
+        assert_eq!(
+            point_segment_distance_squared(Vec2::new(0., 2.), start, end),
+            4.
+        );
+        assert_eq!(
+            point_segment_distance_squared(Vec2::new(4., 4.), start, end),
+            25.
+        );
+        // Degenerate segment
+        assert_eq!(
+            point_segment_distance_squared(Vec2::new(3., 4.), Vec2::ZERO, Vec2::ZERO),
+            25.
+        );
+    }
+
+    #[test]
+    fn test_segments_cross() {
+        let (a1, a2) = (Vec2::new(-1., 0.), Vec2::new(1., 0.));
+        assert!(segments_cross(
+            a1,
+            a2,
+            Vec2::new(0., -1.),
+            Vec2::new(0., 1.)
+        ));
+        assert!(!segments_cross(
+            a1,
+            a2,
+            Vec2::new(2., -1.),
+            Vec2::new(2., 1.)
+        ));
+        // Touching is left to distance checks
+        assert!(!segments_cross(
+            a1,
+            a2,
+            Vec2::new(1., 0.),
+            Vec2::new(1., 1.)
+        ));
+    }
+
+    #[test]
+    fn test_contains_any_winding() {
+        let clockwise = [Vec2::ZERO, Vec2::new(0., 4.), Vec2::new(4., 0.)];
+        let counter_clockwise = [Vec2::ZERO, Vec2::new(4., 0.), Vec2::new(0., 4.)];
+        for polygon in [clockwise, counter_clockwise] {
+            assert!(contains(&polygon, Vec2::new(1., 1.)));
+            assert!(!contains(&polygon, Vec2::new(3., 3.)));
+        }
+        // Segments have no inside
+        assert!(!contains(&[Vec2::ZERO, Vec2::X], Vec2::new(0.5, 0.)));
+    }
+
+    #[test]
+    fn test_core_distance() {
+        let square = [
+            Vec2::new(-1., -1.),
+            Vec2::new(1., -1.),
+            Vec2::new(1., 1.),
+            Vec2::new(-1., 1.),
+        ];
+        let segment = [Vec2::new(3., -5.), Vec2::new(3., 5.)];
+        assert!((core_distance_squared(&square, &segment) - 4.).abs() < EPSILON);
+        assert!((core_distance_squared(&square, &[Vec2::new(4., 5.)]) - 25.).abs() < EPSILON);
+        // Crossing and containment
+        assert_eq!(
+            core_distance_squared(&square, &[Vec2::new(0., -5.), Vec2::new(0., 5.)]),
+            0.
+        );
+        assert_eq!(core_distance_squared(&square, &[Vec2::new(0.5, 0.5)]), 0.);
+    }
+
+    #[test]
+    fn test_rectangle_overlaps() {
+        let circle = CollisionShape::Circle(1.);
+        assert!(bar().collides_with(at(0., 0.), &circle, at(10.5, 0.)));
+        assert!(!bar().collides_with(at(0., 0.), &circle, at(0., 3.)));
+        // Inside the bounding circle, but not the rotated box
+        assert!(bar().collides_with(rotated(0., 0., FRAC_PI_2), &circle, at(0., 9.)));
+        assert!(!bar().collides_with(rotated(0., 0., FRAC_PI_2), &circle, at(9., 0.)));
+        // Fully inside
+        assert!(bar().collides_with(at(0., 0.), &CollisionShape::Point, at(1., 0.5)));
+    }
+
+    #[test]
+    fn test_capsule_and_polygon_overlaps() {
+        let capsule = CollisionShape::Capsule {
+            half_length: 5.,
+            radius: 1.,
+        };
+        assert!(capsule.collides_with(rotated(0., 0., FRAC_PI_2), &bar(), at(0., 0.)));
+        assert!(!capsule.collides_with(at(0., 20.), &bar(), at(0., 0.)));
+        assert!(capsule.collides_with(at(0., 1.9), &bar(), at(0., 0.)));
+
+        assert!(triangle().collides_with(at(0., 0.), &CollisionShape::Point, at(1., 1.)));
+        assert!(!triangle().collides_with(at(0., 0.), &CollisionShape::Point, at(3., 3.)));
+        assert!(triangle().collides_with(at(0., 0.), &CollisionShape::Circle(1.5), at(3., 3.)));
+        assert!(triangle().collides_with(at(0., 0.), &triangle(), at(1., 1.)));
+    }
+
+    #[test]
+    fn test_ray_cast() {
+        let from = Vec2::new(-20., 0.5);
+        let to = Vec2::new(20., 0.5);
+        let time = bar().ray_cast(at(0., 0.), from, to).expect("Should hit");
+        assert!((time - 0.25).abs() < EPSILON);
+
+        let vertical_bar = rotated(0., 0., FRAC_PI_2);
+        assert!(
+            bar()
+                .ray_cast(vertical_bar, Vec2::new(-20., 5.), Vec2::new(20., 5.))
+                .is_some()
+        );
+        // Misses the rotated box despite crossing its bounding circle
+        assert_eq!(
+            bar().ray_cast(vertical_bar, Vec2::new(5., -20.), Vec2::new(5., 20.)),
+            None
+        );
+        // Starting inside
+        assert_eq!(bar().ray_cast(at(0., 0.), Vec2::ZERO, to), None);
+    }
+
+    #[test]
+    fn test_sweep() {
+        let vertical_bar = rotated(0., 0., FRAC_PI_2);
+
+        // Passes through the bounding circle, but next to the bar
+        assert_eq!(
+            bar().sweep(vertical_bar, Vec2::new(5., -20.), Vec2::new(5., 20.), 1.),
+            None
+        );
+        let time = bar()
+            .sweep(vertical_bar, Vec2::new(-20., 0.), Vec2::new(20., 0.), 1.)
+            .expect("Should hit");
+        assert!((time - 0.45).abs() < EPSILON);
+        // Already overlapping
+        assert_eq!(
+            bar().sweep(vertical_bar, Vec2::new(1.5, 0.), Vec2::new(20., 0.), 1.),
+            Some(0.)
+        );
+        // Round shapes add up their radii
+        let time = CollisionShape::Circle(2.)
+            .sweep(at(0., 0.), Vec2::new(-10., 0.), Vec2::new(10., 0.), 1.)
+            .expect("Should hit");
+        assert!((time - 0.35).abs() < EPSILON);
+    }
+}
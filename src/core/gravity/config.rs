@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+
+#[derive(AssetCollection, Resource)]
+pub struct GravityAssets {
+    #[asset(path = "config/config.gravity.ron")]
+    pub config: Handle<GravityConfig>,
+}
+
+#[derive(serde::Deserialize, Asset, TypePath, Debug, Clone, PartialEq)]
+pub struct GravityConfig {
+    /// Gravitational constant
+    pub g: f32,
+    /// Softening length, keeping accelerations finite close to a source
+    pub softening: f32,
+    /// Sources further away than this don't attract. Unlimited if `None`.
+    /// Barnes–Hut aggregates straddling the cutoff are approximated as a whole.
+    pub max_radius: Option<f32>,
+    /// Exponent of the distance in the force denominator, `2.` being Newtonian gravity
+    pub falloff: f32,
+}
+
+impl Default for GravityConfig {
+    fn default() -> Self {
+        Self {
+            g: 800.,
+            softening: 1.,
+            max_radius: None,
+            falloff: 2.,
+        }
+    }
+}
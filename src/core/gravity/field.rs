@@ -0,0 +1,361 @@
+use bevy::{
+    math::{FloatPow as _, ops},
+    prelude::*,
+};
+
+use super::GravityConfig;
+
+/// Maximum amount of bodies held by a tree leaf
+const LEAF_CAPACITY: usize = 4;
+/// Stops subdividing when many bodies share the same position
+const MAX_DEPTH: u32 = 16;
+
+/// A point mass exerting gravity
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Body {
+    pub position: Vec2,
+    pub mass: f32,
+}
+
+/// How gravity sources get aggregated when computing accelerations
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct GravityApproximation {
+    /// Barnes–Hut opening angle. Lower is more accurate, `0.` always uses the exact path.
+    pub theta: f32,
+    /// The exact path is used below this amount of gravity sources
+    pub min_sources: usize,
+}
+
+/// Gravity sources of a frame, ready to be queried for accelerations
+pub struct GravityField {
+    sources: Sources,
+    config: GravityConfig,
+}
+
+enum Sources {
+    Exact(Vec<Body>),
+    BarnesHut { tree: BarnesHutTree, theta: f32 },
+}
+
+/// Quadtree aggregating far away bodies into their center of mass.
+/// Building it from the same ordered bodies always yields the same tree, keeping it rollback-safe.
+pub struct BarnesHutTree {
+    nodes: Vec<Node>,
+    bodies: Vec<Body>,
+}
+
+struct Node {
+    /// All bodies of the node as a single one, positioned at their center of mass
+    aggregate: Body,
+    center: Vec2,
+    size: f32,
+    kind: NodeKind,
+}
+
+enum NodeKind {
+    /// Range of bodies in `BarnesHutTree::bodies`
+    Leaf(usize, usize),
+    Internal([usize; 4]),
+}
+
+impl Default for GravityApproximation {
+    fn default() -> Self {
+        Self {
+            theta: 0.5,
+            min_sources: 32,
+        }
+    }
+}
+
+impl GravityField {
+    pub fn new(
+        bodies: Vec<Body>,
+        config: &GravityConfig,
+        approximation: &GravityApproximation,
+    ) -> Self {
+        let sources = if approximation.theta <= 0. || bodies.len() < approximation.min_sources {
+            Sources::Exact(bodies)
+        } else {
+            Sources::BarnesHut {
+                tree: BarnesHutTree::new(bodies),
+                theta: approximation.theta,
+            }
+        };
+
+        Self {
+            sources,
+            config: config.clone(),
+        }
+    }
+
+    pub fn acceleration(&self, position: Vec2) -> Vec2 {
+        match &self.sources {
+            Sources::Exact(bodies) => exact_acceleration(bodies, position, &self.config),
+            Sources::BarnesHut { tree, theta } => tree.acceleration(position, *theta, &self.config),
+        }
+    }
+}
+
+impl BarnesHutTree {
+    pub fn new(bodies: Vec<Body>) -> Self {
+        let mut tree = Self {
+            nodes: Vec::with_capacity(bodies.len() / LEAF_CAPACITY * 2),
+            bodies,
+        };
+        if tree.bodies.is_empty() {
+            return tree;
+        }
+
+        let (min, max) = tree
+            .bodies
+            .iter()
+            .fold((Vec2::MAX, Vec2::MIN), |(min, max), body| {
+                (min.min(body.position), max.max(body.position))
+            });
+        let size = (max - min).max_element().max(1.);
+        tree.build(0, tree.bodies.len(), (min + max) / 2., size, 0);
+
+        tree
+    }
+
+    pub fn acceleration(&self, position: Vec2, theta: f32, config: &GravityConfig) -> Vec2 {
+        if self.nodes.is_empty() {
+            return Vec2::ZERO;
+        }
+
+        self.node_acceleration(0, position, theta * theta, config)
+    }
+
+    fn build(&mut self, start: usize, end: usize, center: Vec2, size: f32, depth: u32) -> usize {
+        let bodies = &mut self.bodies[start..end];
+        let mass = bodies.iter().map(|body| body.mass).sum::<f32>();
+        let weighted_position = bodies
+            .iter()
+            .fold(Vec2::ZERO, |acc, body| acc + body.position * body.mass);
+
+        let index = self.nodes.len();
+        self.nodes.push(Node {
+            aggregate: Body {
+                position: if mass > 0. {
+                    weighted_position / mass
+                } else {
+                    center
+                },
+                mass,
+            },
+            center,
+            size,
+            kind: NodeKind::Leaf(start, end),
+        });
+
+        if bodies.len() <= LEAF_CAPACITY || depth >= MAX_DEPTH {
+            return index;
+        }
+
+        // Stable sort, so that bodies order within a quadrant is kept
+        bodies.sort_by_key(|body| quadrant(center, body.position));
+
+        let mut children = [0; 4];
+        let mut child_start = start;
+        for (i, child) in children.iter_mut().enumerate() {
+            let child_end = child_start
+                + self.bodies[child_start..end]
+                    .iter()
+                    .take_while(|body| quadrant(center, body.position) == i)
+                    .count();
+            let offset = Vec2::new(
+                if i & 1 == 1 { 1. } else { -1. },
+                if i & 2 == 2 { 1. } else { -1. },
+            ) * size
+                / 4.;
+
+            *child = self.build(
+                child_start,
+                child_end,
+                center + offset,
+                size / 2.,
+                depth + 1,
+            );
+            child_start = child_end;
+        }
+        self.nodes[index].kind = NodeKind::Internal(children);
+
+        index
+    }
+
+    fn node_acceleration(
+        &self,
+        index: usize,
+        position: Vec2,
+        theta_squared: f32,
+        config: &GravityConfig,
+    ) -> Vec2 {
+        let node = &self.nodes[index];
+        if node.aggregate.mass == 0. {
+            return Vec2::ZERO;
+        }
+
+        // The whole node is out of range
+        let box_distance = ((position - node.center).abs() - node.size / 2.).max(Vec2::ZERO);
+        if config
+            .max_radius
+            .is_some_and(|max_radius| box_distance.length_squared() > max_radius.squared())
+        {
+            return Vec2::ZERO;
+        }
+
+        match node.kind {
+            NodeKind::Leaf(start, end) => {
+                exact_acceleration(&self.bodies[start..end], position, config)
+            }
+            NodeKind::Internal(children) => {
+                let is_inside = (position - node.center).abs().max_element() <= node.size / 2.;
+                let distance_squared = position.distance_squared(node.aggregate.position);
+
+                if !is_inside && node.size * node.size < theta_squared * distance_squared {
+                    body_acceleration(position, &node.aggregate, config)
+                } else {
+                    children.iter().fold(Vec2::ZERO, |acc, child| {
+                        acc + self.node_acceleration(*child, position, theta_squared, config)
+                    })
+                }
+            }
+        }
+    }
+}
+
+/// Sums the accelerations exerted by every body on `position`
+pub fn exact_acceleration(bodies: &[Body], position: Vec2, config: &GravityConfig) -> Vec2 {
+    bodies.iter().fold(Vec2::ZERO, |acc, body| {
+        acc + body_acceleration(position, body, config)
+    })
+}
+
+fn body_acceleration(position: Vec2, body: &Body, config: &GravityConfig) -> Vec2 {
+    if position == body.position {
+        return Vec2::ZERO;
+    }
+
+    let offset = body.position - position;
+    let distance_squared = offset.length_squared();
+    if config
+        .max_radius
+        .is_some_and(|max_radius| distance_squared > max_radius.squared())
+    {
+        return Vec2::ZERO;
+    }
+
+    let softened = distance_squared + config.softening.squared();
+    let denominator = if config.falloff == 2. {
+        softened
+    } else {
+        ops::powf(softened, config.falloff / 2.)
+    };
+
+    config.g * body.mass / denominator * offset.normalize()
+}
+
+fn quadrant(center: Vec2, position: Vec2) -> usize {
+    (position.x >= center.x) as usize | ((position.y >= center.y) as usize) << 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Allowed error, relative to the summed magnitudes of every body's pull
+    const TOLERANCE: f32 = 0.05;
+
+    /// Bodies spread along a spiral, with uneven masses
+    fn spiral(count: usize, center: Vec2, spread: f32) -> Vec<Body> {
+        (0..count)
+            .map(|i| Body {
+                position: center
+                    + Vec2::from_angle(i as f32 * 2.4) * spread * ops::sqrt(i as f32 + 1.),
+                mass: 1. + (i % 7) as f32,
+            })
+            .collect()
+    }
+
+    fn assert_matches_exact(bodies: Vec<Body>, probes: impl IntoIterator<Item = Vec2>) {
+        let config = GravityConfig::default();
+        let theta = GravityApproximation::default().theta;
+        let tree = BarnesHutTree::new(bodies.clone());
+
+        for probe in probes {
+            let exact = exact_acceleration(&bodies, probe, &config);
+            let scale = bodies
+                .iter()
+                .map(|body| body_acceleration(probe, body, &config).length())
+                .sum::<f32>();
+            let approximated = tree.acceleration(probe, theta, &config);
+            assert!(
+                (approximated - exact).length() <= TOLERANCE * scale,
+                "{approximated} too far from {exact} at {probe}"
+            );
+        }
+    }
+
+    fn grid(half_extent: f32, steps: i32) -> impl Iterator<Item = Vec2> {
+        (-steps..=steps).flat_map(move |x| {
+            (-steps..=steps)
+                .map(move |y| Vec2::new(x as f32, y as f32) * half_extent / steps as f32)
+        })
+    }
+
+    #[test]
+    fn test_disc() {
+        let bodies = spiral(200, Vec2::ZERO, 20.);
+        let probes = bodies
+            .iter()
+            .map(|body| body.position)
+            .chain(grid(1000., 5))
+            .collect::<Vec<_>>();
+        assert_matches_exact(bodies, probes);
+    }
+
+    #[test]
+    fn test_distant_clusters() {
+        let mut bodies = spiral(100, Vec2::new(-3000., 0.), 5.);
+        bodies.extend(spiral(100, Vec2::new(3000., 500.), 5.));
+        let probes = bodies
+            .iter()
+            .map(|body| body.position)
+            .chain(grid(4000., 4))
+            .collect::<Vec<_>>();
+        assert_matches_exact(bodies, probes);
+    }
+
+    #[test]
+    fn test_stacked_bodies() {
+        // More bodies share a position than a leaf holds, capped by `MAX_DEPTH`
+        let mut bodies = vec![
+            Body {
+                position: Vec2::new(100., 100.),
+                mass: 5.,
+            };
+            20
+        ];
+        bodies.extend(spiral(50, Vec2::ZERO, 30.));
+        let probes = bodies
+            .iter()
+            .map(|body| body.position)
+            .chain(grid(500., 5))
+            .collect::<Vec<_>>();
+        assert_matches_exact(bodies, probes);
+    }
+
+    #[test]
+    fn test_zero_theta_is_exact() {
+        let bodies = spiral(100, Vec2::ZERO, 20.);
+        let config = GravityConfig::default();
+        let tree = BarnesHutTree::new(bodies.clone());
+
+        for probe in grid(500., 5) {
+            let exact = exact_acceleration(&bodies, probe, &config);
+            let approximated = tree.acceleration(probe, 0., &config);
+            assert!((approximated - exact).length() <= 1e-4 * exact.length().max(1.));
+        }
+    }
+}
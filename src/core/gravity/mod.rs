@@ -0,0 +1,102 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use super::physics::{Acceleration, ForceSet, PhysicsStep, Position};
+
+mod config;
+mod field;
+pub use config::*;
+pub use field::*;
+
+#[derive(Component, Debug, Reflect, Clone, PartialEq)]
+pub struct Mass(pub u32);
+
+#[derive(Component, Debug, Default, Reflect, Clone, PartialEq)]
+pub struct Passive;
+
+#[derive(Component, Debug, Default, Reflect, Clone, PartialEq)]
+pub struct Static;
+
+/// Multiplies the gravity exerted by a source
+#[derive(Component, Debug, Reflect, Clone, Copy, PartialEq)]
+pub struct GravityScale(pub f32);
+
+/// Multiplies the gravity an entity is subject to
+#[derive(Component, Debug, Reflect, Clone, Copy, PartialEq)]
+pub struct GravityReceiverScale(pub f32);
+
+pub struct GravityPlugin;
+
+impl Plugin for GravityPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Mass>()
+            .register_type::<GravityScale>()
+            .register_type::<GravityReceiverScale>()
+            .register_type::<GravityApproximation>()
+            .init_resource::<GravityApproximation>()
+            .add_systems(
+                PhysicsStep,
+                apply_forces
+                    .in_set(ForceSet)
+                    .run_if(resource_exists::<GravityAssets>),
+            );
+    }
+}
+
+/// Everything needed to build the `GravityField` of the current frame.
+/// Shared with predictions, so that they match the rollback schedule.
+#[derive(SystemParam)]
+pub struct GravitySources<'w, 's> {
+    sources: Query<
+        'w,
+        's,
+        (
+            &'static Mass,
+            &'static Position,
+            Option<&'static GravityScale>,
+        ),
+        Without<Passive>,
+    >,
+    approximation: Res<'w, GravityApproximation>,
+    assets: Res<'w, GravityAssets>,
+    configs: Res<'w, Assets<GravityConfig>>,
+}
+
+impl GravitySources<'_, '_> {
+    /// Returns `None` if the config isn't loaded yet
+    pub fn field(&self) -> Option<GravityField> {
+        let config = self.configs.get(&self.assets.config)?;
+
+        // Sorted, as summation order must be the same on every peer
+        let sources = self
+            .sources
+            .iter()
+            .sort::<&Position>()
+            .map(|(mass, position, scale)| Body {
+                position: position.0,
+                mass: mass.0 as f32 * scale.map_or(1., |scale| scale.0),
+            })
+            .collect();
+
+        Some(GravityField::new(sources, config, &self.approximation))
+    }
+}
+
+fn apply_forces(
+    mut on: Query<
+        (&Position, &mut Acceleration, Option<&GravityReceiverScale>),
+        (With<Mass>, Without<Static>),
+    >,
+    sources: GravitySources,
+) {
+    // Runs on every physics sub-step, so stays quiet until the config is loaded
+    let Some(field) = sources.field() else {
+        return;
+    };
+
+    // `for_each` is more performant than a standard for loop
+    on.iter_mut()
+        .for_each(|(position, mut acceleration, receiver_scale)| {
+            acceleration.0 +=
+                field.acceleration(position.0) * receiver_scale.map_or(1., |scale| scale.0);
+        });
+}
@@ -1,11 +1,17 @@
+use std::f32::consts::PI;
+
 use bevy::prelude::*;
-use bevy_ggrs::{LocalPlayers, ReadInputs};
+use bevy_ggrs::{LocalPlayers, ReadInputs, RollbackFrameCount};
 use leafwing_input_manager::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xoshiro::Xoshiro256PlusPlus;
 
-use crate::entities::player::Player;
+use crate::{entities::player::Player, network::SessionSeed};
 
 /// Threshold for stick inputs to be acknowledged
 const GAMEPAD_THRESHOLD: f32 = 0.5;
+/// Number of frames a random bot sticks to its decisions
+const BOT_DECISION_FRAMES: i32 = 20;
 
 pub type PlayerActionState = ActionState<PlayerAction>;
 
@@ -25,6 +31,8 @@ pub enum PlayerAction {
     PointerDirection,
     Reload,
     Interact,
+    /// Local only, never serialized
+    TogglePreview,
 }
 
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
@@ -35,11 +43,19 @@ pub enum InputSet {
     Serialize,
 }
 
+/// Signature of an input script: `(session_seed, frame, player_handle, action_state)`.
+pub type InputScript = dyn Fn(u64, i32, usize, &mut PlayerActionState) + Send + Sync;
+
+/// When present, overrides the `PlayerActionState` of local players with the output of a script.
+/// Used to drive bots, notably in headless mode.
+#[derive(Resource)]
+pub struct ScriptedInputs(pub Box<InputScript>);
+
+/// Local input devices handling
 pub struct InputsPlugin;
 impl Plugin for InputsPlugin {
     fn build(&self, app: &mut App) {
-        app.configure_sets(ReadInputs, (InputSet::Update, InputSet::Serialize).chain())
-            .add_plugins(InputManagerPlugin::<PlayerAction>::default())
+        app.add_plugins(InputManagerPlugin::<PlayerAction>::default())
             .add_systems(
                 ReadInputs,
                 update_local_pointer_direction.in_set(InputSet::Update),
@@ -47,6 +63,19 @@ impl Plugin for InputsPlugin {
     }
 }
 
+pub struct ScriptedInputsPlugin;
+impl Plugin for ScriptedInputsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            ReadInputs,
+            apply_scripted_inputs
+                .in_set(InputSet::Update)
+                .after(update_local_pointer_direction)
+                .run_if(resource_exists::<ScriptedInputs>),
+        );
+    }
+}
+
 fn update_local_pointer_direction(
     mut player_query: Query<(&Player, &GlobalTransform, &mut PlayerActionState)>,
     windows: Query<&Window>,
@@ -77,6 +106,66 @@ fn update_local_pointer_direction(
     Ok(())
 }
 
+fn apply_scripted_inputs(
+    mut query: Query<(&Player, &mut PlayerActionState)>,
+    script: Res<ScriptedInputs>,
+    local_players: Res<LocalPlayers>,
+    frame: Res<RollbackFrameCount>,
+    seed: Option<Res<SessionSeed>>,
+) {
+    let seed = seed.map(|seed| seed.0).unwrap_or_default();
+
+    for (player, mut action_state) in query
+        .iter_mut()
+        .filter(|(player, _)| local_players.0.contains(&player.handle))
+    {
+        (script.0)(seed, frame.0, player.handle, &mut action_state);
+    }
+}
+
+impl ScriptedInputs {
+    pub fn new(
+        script: impl Fn(u64, i32, usize, &mut PlayerActionState) + Send + Sync + 'static,
+    ) -> Self {
+        Self(Box::new(script))
+    }
+
+    /// A bot mashing random buttons, deterministic for a given session seed.
+    pub fn random_bot() -> Self {
+        Self::new(random_bot_script)
+    }
+}
+
+fn random_bot_script(seed: u64, frame: i32, handle: usize, action_state: &mut PlayerActionState) {
+    let decision = (frame / BOT_DECISION_FRAMES) as u64;
+    let mut rng =
+        Xoshiro256PlusPlus::seed_from_u64(seed ^ (handle as u64).rotate_left(32) ^ decision);
+
+    for (action, probability) in [
+        (PlayerAction::Jump, 0.2),
+        (PlayerAction::Sneak, 0.1),
+        (PlayerAction::Left, 0.4),
+        (PlayerAction::Right, 0.4),
+        (PlayerAction::Shoot, 0.5),
+        (PlayerAction::Reload, 0.05),
+        (PlayerAction::Interact, 0.1),
+        (PlayerAction::Slot1, 0.05),
+        (PlayerAction::Slot2, 0.05),
+        (PlayerAction::Slot3, 0.05),
+    ] {
+        if rng.random_bool(probability) {
+            action_state.press(&action);
+        } else {
+            action_state.release(&action);
+        }
+    }
+
+    action_state.set_axis_pair(
+        &PlayerAction::PointerDirection,
+        Vec2::from_angle(rng.random_range(-PI..PI)),
+    );
+}
+
 pub fn default_input_map() -> InputMap<PlayerAction> {
     InputMap::new([
         // Jump
@@ -97,6 +186,8 @@ pub fn default_input_map() -> InputMap<PlayerAction> {
         (PlayerAction::Reload, KeyCode::KeyR),
         // Interaction
         (PlayerAction::Interact, KeyCode::KeyE),
+        // Trajectory preview
+        (PlayerAction::TogglePreview, KeyCode::KeyT),
     ])
     // Mouse
     .with(PlayerAction::Shoot, MouseButton::Left)
@@ -110,6 +201,7 @@ pub fn default_input_map() -> InputMap<PlayerAction> {
         (PlayerAction::Shoot, GamepadButton::RightTrigger2),
         (PlayerAction::Reload, GamepadButton::West),
         (PlayerAction::Interact, GamepadButton::East),
+        (PlayerAction::TogglePreview, GamepadButton::Select),
         (PlayerAction::SlotNext, GamepadButton::RightTrigger),
         (PlayerAction::SlotPrev, GamepadButton::LeftTrigger),
     ])
@@ -7,16 +7,28 @@ pub mod collision;
 pub mod gravity;
 pub mod inputs;
 pub mod physics;
+pub mod rng;
 pub mod worldgen;
+
+/// Core simulation plugins. Don't depend on a window nor a renderer.
 pub struct CorePlugins;
 impl Plugin for CorePlugins {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(gravity::GravityPlugin)
+            .add_plugins(inputs::ScriptedInputsPlugin)
+            .add_plugins(physics::PhysicsPlugin)
+            .add_plugins(rng::RngPlugin)
+            .add_plugins(worldgen::WorldgenPlugin);
+    }
+}
+
+/// Core plugins handling rendering, audio and local input devices.
+pub struct CoreClientPlugins;
+impl Plugin for CoreClientPlugins {
     fn build(&self, app: &mut App) {
         app.add_plugins(background::BackgroundPlugin)
             .add_plugins(camera::CameraPlugin)
             .add_plugins(audio::GameAudioPlugin)
-            .add_plugins(gravity::GravityPlugin)
-            .add_plugins(inputs::InputsPlugin)
-            .add_plugins(physics::PhysicsPlugin)
-            .add_plugins(worldgen::WorldgenPlugin);
+            .add_plugins(inputs::InputsPlugin);
     }
 }
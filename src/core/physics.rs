@@ -1,4 +1,4 @@
-use bevy::prelude::*;
+use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
 use bevy_ggrs::GgrsSchedule;
 
 use crate::GameState;
@@ -8,8 +8,14 @@ use crate::GameState;
 pub struct Position(pub Vec2);
 
 #[derive(Component, Debug, Default, Reflect, Clone, PartialEq, Deref, DerefMut)]
+#[require(Acceleration)]
 pub struct Velocity(pub Vec2);
 
+/// Acceleration from continuous forces, evaluated in `PhysicsStep`.
+/// Recomputed before each use, so it never needs to be rolled back.
+#[derive(Component, Debug, Default, Reflect, Clone, PartialEq, Deref, DerefMut)]
+pub struct Acceleration(pub Vec2);
+
 #[derive(Component, Debug, Default, Reflect, Clone, PartialEq, Deref, DerefMut)]
 pub struct Rotation(pub f32);
 
@@ -24,22 +30,88 @@ pub struct PhysicsBundle {
 pub enum PhysicsSet {
     /// Where player inputs are processed
     Player,
-    /// Where Velocity gets updated
+    /// Where Velocity gets updated from continuous forces, when the `Integrator` allows it.
+    /// Gravity itself is evaluated in `PhysicsStep`.
     Gravity,
     /// Where entities interact with each other
     Interaction,
-    /// Where Position gets updated
+    /// Where Position gets updated, along with Velocity from continuous forces
     Movement,
     /// Where collision detection systems are run
     Collision,
 }
 
+/// Schedule evaluating continuous forces into `Acceleration`.
+/// Run at least once per sub-step during `PhysicsSet::Movement`, depending on the `Integrator`.
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PhysicsStep;
+
+/// Where continuous forces add up to `Acceleration` in `PhysicsStep`
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ForceSet;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum Integrator {
+    /// Velocity of the first sub-step gets updated in `PhysicsSet::Gravity`,
+    /// before entities interact, the remaining ones in `PhysicsSet::Movement`.
+    #[default]
+    SemiImplicitEuler,
+    /// Second order and symplectic, keeps orbits from gaining energy.
+    /// Evaluates forces twice per sub-step, all of them in `PhysicsSet::Movement`.
+    VelocityVerlet,
+}
+
+/// How `Position` and `Velocity` get integrated each rollback frame
+#[derive(Resource, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct Integration {
+    pub integrator: Integrator,
+    /// Amount of sub-steps per rollback frame
+    pub substeps: u32,
+}
+
+impl Integrator {
+    /// Integrates a single body over `delta`, mirroring what `PhysicsSet::Movement` does to the world.
+    /// Used for predictions, `acceleration` being evaluated at the given position.
+    pub fn step(
+        &self,
+        position: &mut Vec2,
+        velocity: &mut Vec2,
+        delta: f32,
+        acceleration: impl Fn(Vec2) -> Vec2,
+    ) {
+        match self {
+            Integrator::SemiImplicitEuler => {
+                *velocity += acceleration(*position) * delta;
+                *position += *velocity * delta;
+            }
+            Integrator::VelocityVerlet => {
+                *velocity += acceleration(*position) * delta / 2.;
+                *position += *velocity * delta;
+                *velocity += acceleration(*position) * delta / 2.;
+            }
+        }
+    }
+}
+
+impl Default for Integration {
+    fn default() -> Self {
+        Self {
+            integrator: Integrator::default(),
+            substeps: 1,
+        }
+    }
+}
+
 pub struct PhysicsPlugin;
 impl Plugin for PhysicsPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<Position>()
             .register_type::<Velocity>()
             .register_type::<Rotation>()
+            .register_type::<Acceleration>()
+            .register_type::<Integration>()
+            .init_resource::<Integration>()
             .configure_sets(
                 GgrsSchedule,
                 (
@@ -52,23 +124,95 @@ impl Plugin for PhysicsPlugin {
                     .chain()
                     .run_if(in_state(GameState::InGame)),
             )
-            .add_systems(GgrsSchedule, update_position.in_set(PhysicsSet::Movement))
+            .add_systems(PhysicsStep, reset_accelerations.before(ForceSet))
+            .add_systems(
+                GgrsSchedule,
+                (
+                    first_kick.in_set(PhysicsSet::Gravity),
+                    integrate.in_set(PhysicsSet::Movement),
+                ),
+            )
             .add_systems(
                 Update,
                 update_spatial_bundles
                     .in_set(PhysicsSet::Movement)
-                    .after(update_position),
+                    .after(integrate),
             );
     }
 }
 
-fn update_position(mut query: Query<(&mut Position, &Velocity)>, time: Res<Time>) {
-    // `for_each` is more performant than a standard for loop
-    query.iter_mut().for_each(|(mut position, velocity)| {
-        position.0 += velocity.0 * time.delta_secs();
+/// Sub-step duration and amount
+fn substeps(world: &World) -> (f32, u32) {
+    let substeps = world.resource::<Integration>().substeps.max(1);
+    (
+        world.resource::<Time>().delta_secs() / substeps as f32,
+        substeps,
+    )
+}
+
+/// Kicks the first Euler sub-step ahead of `PhysicsSet::Interaction`,
+/// so that interactions see and override the velocity gained from forces.
+fn first_kick(world: &mut World) {
+    if world.resource::<Integration>().integrator != Integrator::SemiImplicitEuler {
+        return;
+    }
+    let (delta, _) = substeps(world);
+    world.run_schedule(PhysicsStep);
+    kick(world, delta);
+}
+
+fn integrate(world: &mut World) {
+    let integrator = world.resource::<Integration>().integrator;
+    let (delta, substeps) = substeps(world);
+
+    for substep in 0..substeps {
+        match integrator {
+            Integrator::SemiImplicitEuler => {
+                // The first kick happened in `PhysicsSet::Gravity`
+                if substep > 0 {
+                    world.run_schedule(PhysicsStep);
+                    kick(world, delta);
+                }
+                drift(world, delta);
+            }
+            Integrator::VelocityVerlet => {
+                world.run_schedule(PhysicsStep);
+                kick(world, delta / 2.);
+                drift(world, delta);
+                world.run_schedule(PhysicsStep);
+                kick(world, delta / 2.);
+            }
+        }
+    }
+}
+
+fn reset_accelerations(mut query: Query<&mut Acceleration>) {
+    query.iter_mut().for_each(|mut acceleration| {
+        acceleration.0 = Vec2::ZERO;
     });
 }
 
+/// Updates `Velocity` from `Acceleration`
+fn kick(world: &mut World, delta: f32) {
+    // `for_each` is more performant than a standard for loop
+    world
+        .query::<(&mut Velocity, &Acceleration)>()
+        .iter_mut(world)
+        .for_each(|(mut velocity, acceleration)| {
+            velocity.0 += acceleration.0 * delta;
+        });
+}
+
+/// Updates `Position` from `Velocity`
+fn drift(world: &mut World, delta: f32) {
+    world
+        .query::<(&mut Position, &Velocity)>()
+        .iter_mut(world)
+        .for_each(|(mut position, velocity)| {
+            position.0 += velocity.0 * delta;
+        });
+}
+
 fn update_spatial_bundles(mut query: Query<(&mut Transform, &Position, Option<&Rotation>)>) {
     // `for_each` is more performant than a standard for loop
     query
@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+use rand::SeedableRng as _;
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+use crate::{GameState, network::SessionSeed};
+
+/// What random draws are used for. Each purpose gets its own stream, so that drawing more
+/// for one of them doesn't shift the others.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+pub enum RngStream {
+    /// Weapon spread
+    Spread,
+    /// Player spawn points
+    Spawns,
+    /// Crate spawn times, places and contents
+    Pickups,
+    /// Map generation, drawn outside of the rollback schedule
+    Worldgen,
+}
+
+impl RngStream {
+    const ALL: [Self; 4] = [Self::Spread, Self::Spawns, Self::Pickups, Self::Worldgen];
+
+    /// Initial state of the stream for `seed`, for draws happening outside of the rollback schedule
+    pub fn rng(self, seed: u64) -> Xoshiro256PlusPlus {
+        stream_rng(seed, self)
+    }
+}
+
+/// Rollback-snapshotted random number generators derived from the `SessionSeed`.
+///
+/// Systems drawing from the same stream must have a fixed order relative to each other,
+/// and iterate queries in a deterministic order (e.g. sorted by player handle) while drawing.
+#[derive(Resource, Clone)]
+pub struct RollbackRng {
+    streams: [Xoshiro256PlusPlus; RngStream::ALL.len()],
+}
+
+impl RollbackRng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            streams: RngStream::ALL.map(|stream| stream_rng(seed, stream)),
+        }
+    }
+
+    pub fn stream(&mut self, stream: RngStream) -> &mut Xoshiro256PlusPlus {
+        &mut self.streams[stream as usize]
+    }
+}
+
+/// Streams are 2^128 draws apart from each other, so they never overlap
+fn stream_rng(seed: u64, stream: RngStream) -> Xoshiro256PlusPlus {
+    let mut rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+    for _ in 0..stream as usize {
+        rng.jump();
+    }
+    rng
+}
+
+pub struct RngPlugin;
+impl Plugin for RngPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<RngStream>()
+            .add_systems(
+                OnEnter(GameState::WorldGen),
+                init_rng.run_if(resource_exists::<SessionSeed>),
+            )
+            .add_systems(OnExit(GameState::Results), remove_rng);
+    }
+}
+
+fn init_rng(mut commands: Commands, seed: Res<SessionSeed>) {
+    commands.insert_resource(RollbackRng::new(seed.0));
+}
+
+fn remove_rng(mut commands: Commands) {
+    commands.remove_resource::<RollbackRng>();
+}
@@ -3,13 +3,12 @@ use std::f32::consts::PI;
 use bevy::prelude::*;
 use bevy_asset_loader::asset_collection::AssetCollection;
 use rand::Rng;
-use rand_xoshiro::{Xoshiro256PlusPlus, rand_core::SeedableRng as _};
 use serde::{Deserialize, Serialize};
 
 use crate::entities::planet::{PlanetType, Radius, SpawnPlanetEvent};
 use crate::entities::satellite::{SatelliteKind, SpawnSatelliteEvent};
 
-use super::physics::Position;
+use super::{physics::Position, rng::RngStream};
 
 pub struct WorldgenPlugin;
 impl Plugin for WorldgenPlugin {
@@ -78,7 +77,7 @@ fn handle_genworld_event(
     };
 
     for GenerateWorldEvent { seed } in events.read() {
-        let mut rng = Xoshiro256PlusPlus::seed_from_u64(*seed);
+        let mut rng = RngStream::Worldgen.rng(*seed);
         let mut planets = Vec::new();
         planets.push(SpawnPlanetEvent {
             position: Position(Vec2::ZERO),
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+
+#[derive(AssetCollection, Resource)]
+pub struct BlackHoleAssets {
+    #[asset(path = "config/config.blackhole.ron")]
+    pub config: Handle<BlackHoleConfig>,
+}
+
+#[derive(serde::Deserialize, Asset, TypePath)]
+pub struct BlackHoleConfig {
+    pub mass: u32,
+    /// Event horizon radius
+    pub radius: u32,
+    /// Seconds before the black hole vanishes
+    pub lifetime: f32,
+    /// What happens to players crossing the event horizon
+    pub horizon: HorizonEffect,
+    /// Fraction of swallowed mass added to the black hole. `0.` disables growth
+    pub growth: f32,
+    /// Radius the black hole can't grow past
+    pub max_radius: u32,
+}
+
+#[derive(serde::Deserialize, Clone, Copy, Debug)]
+pub enum HorizonEffect {
+    /// Player dies instantly
+    Knockout,
+    /// Player percentage gets increased by this amount
+    Damage(f32),
+}
@@ -1,147 +1,173 @@
-use super::planet::{Radius, materials::CommonMaterial};
-use super::projectile::config::BLACKHOLE_RADIUS;
+use super::planet::Radius;
+use super::player::{Percentage, Player};
 use super::projectile::{DecayTimer, ProjectileDecayedEvent};
 use crate::core::{
+    collision::{CollisionPlugin, CollisionShape, CollisionStarted, check_collisions},
     gravity::{Mass, Static},
     physics::PhysicsSet,
 };
 use crate::entities::projectile::Projectile;
-use bevy::{prelude::*, sprite::Material2dPlugin};
+use crate::level::{
+    kills::{self, LastHitBy, Shooter},
+    limit::{self, DeathEvent},
+};
+use crate::network::events::{RollbackEventReader, RollbackEventWriter};
+use bevy::prelude::*;
 use bevy_ggrs::{AddRollbackCommandExtension, GgrsSchedule};
 
+pub mod config;
 mod visuals;
-use visuals::*;
-
-// TODO: move to config
-const BLACKHOLE_MASS: u32 = 100000;
-const BH_DECAY_TIME: f32 = 10.;
+use config::{BlackHoleAssets, BlackHoleConfig, HorizonEffect};
+pub use visuals::BlackHoleVisualsPlugin;
 
 #[derive(Component, Debug, Reflect, Clone, Copy, PartialEq)]
 #[require(Visibility)]
 pub struct BlackHole;
 
+/// Mass swallowed so far, kept apart from `Mass` so that fractions of it add up
+#[derive(Component, Debug, Default, Reflect, Clone, Copy, PartialEq)]
+pub struct SwallowedMass(pub f32);
+
 pub struct BlackHolePlugin;
 impl Plugin for BlackHolePlugin {
     fn build(&self, app: &mut App) {
-        app.register_required_components::<BlackHole, Static>()
+        app.register_type::<SwallowedMass>()
+            .register_required_components::<BlackHole, Static>()
+            .register_required_components::<BlackHole, SwallowedMass>()
             .register_required_components_with::<BlackHole, Name>(|| Name::new("Blackhole"))
-            .register_required_components_with::<BlackHole, Mass>(|| Mass(BLACKHOLE_MASS))
-            .register_required_components_with::<BlackHole, Radius>(|| Radius(BLACKHOLE_RADIUS))
-            .add_plugins(Material2dPlugin::<BlackHoleMaterial>::default())
-            .add_plugins(Material2dPlugin::<BlackHoleRingMaterial>::default())
-            .add_systems(Update, add_visuals)
+            .add_plugins(CollisionPlugin::<Projectile, BlackHole>::new())
+            .add_plugins(CollisionPlugin::<Player, BlackHole>::new())
             .add_systems(
                 GgrsSchedule,
-                handle_blackhole_projectile_decay
-                    // Needs to run after `projectile::tick_decay_timers`
-                    .after(PhysicsSet::Player)
-                    .before(PhysicsSet::Gravity),
+                (
+                    handle_blackhole_projectile_decay
+                        // Projectiles can also decay from projectile interactions
+                        .after(PhysicsSet::Movement)
+                        .before(PhysicsSet::Collision),
+                    (swallow_projectiles, swallow_players)
+                        .chain()
+                        .in_set(PhysicsSet::Collision)
+                        .after(check_collisions::<Projectile, BlackHole>)
+                        .after(check_collisions::<Player, BlackHole>)
+                        // Deaths get handled on the same frame
+                        .before(limit::check_outsiders),
+                )
+                    .run_if(resource_exists::<BlackHoleAssets>),
             );
     }
 }
 
 fn handle_blackhole_projectile_decay(
     mut commands: Commands,
-    mut events: EventReader<ProjectileDecayedEvent>,
+    events: RollbackEventReader<ProjectileDecayedEvent>,
+    assets: Res<BlackHoleAssets>,
+    configs: Res<Assets<BlackHoleConfig>>,
 ) {
+    let Some(config) = configs.get(&assets.config) else {
+        warn!("Couldn't load BlackHoleConfig");
+        return;
+    };
+
     for event in events.read() {
         if let Some(r#type) = event.r#type
             && let Projectile::Blackhole = r#type
         {
-            commands
-                .spawn((
-                    BlackHole,
-                    event.position.clone(),
-                    DecayTimer(Timer::from_seconds(BH_DECAY_TIME, TimerMode::Once)),
-                ))
-                .add_rollback();
+            let mut black_hole = commands.spawn((
+                BlackHole,
+                event.position.clone(),
+                Mass(config.mass),
+                Radius(config.radius),
+                CollisionShape::Circle(config.radius as f32),
+                DecayTimer(Timer::from_seconds(config.lifetime, TimerMode::Once)),
+            ));
+            if let Some(shooter) = event.shooter {
+                black_hole.insert(shooter);
+            }
+            black_hole.add_rollback();
         }
     }
 }
 
-fn add_visuals(
+/// Projectiles crossing the event horizon vanish, and make the black hole grow
+fn swallow_projectiles(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut core_materials: ResMut<Assets<BlackHoleMaterial>>,
-    mut ring_materials: ResMut<Assets<BlackHoleRingMaterial>>,
-    query: Query<(Entity, &Radius), (With<BlackHole>, Without<Children>)>,
+    mut collisions: EventReader<CollisionStarted<Projectile, BlackHole>>,
+    projectile_query: Query<Option<&Mass>, With<Projectile>>,
+    mut black_hole_query: Query<
+        (
+            &mut Mass,
+            &mut SwallowedMass,
+            &mut Radius,
+            &mut CollisionShape,
+        ),
+        With<BlackHole>,
+    >,
+    assets: Res<BlackHoleAssets>,
+    configs: Res<Assets<BlackHoleConfig>>,
 ) {
-    for (black_hole, bh_radius) in query.iter() {
-        let mut common = CommonMaterial {
-            pixels: f32::min(bh_radius.0 as f32 / 2., 200.),
-            seed: 69.,
-            ..Default::default()
+    let Some(config) = configs.get(&assets.config) else {
+        warn!("Couldn't load BlackHoleConfig");
+        return;
+    };
+
+    for collision in collisions.read() {
+        let Ok(projectile_mass) = projectile_query.get(collision.entity) else {
+            continue;
         };
+        commands.entity(collision.entity).try_despawn();
 
-        // Material spesific
-        common.size = CORE_SIZE;
-        common.octaves = CORE_OCTAVES;
+        let Ok((mut mass, mut swallowed, mut radius, mut shape)) =
+            black_hole_query.get_mut(collision.other)
+        else {
+            continue;
+        };
+        if config.growth <= 0. || config.mass == 0 {
+            continue;
+        }
 
-        let core = core_materials.add(BlackHoleMaterial {
-            common,
-            radius: CORE_RADIUS,
-            light_width: CORE_LIGHT_WIDTH,
-            color_core: Srgba::hex(CORE_PALETTE[0]).unwrap().into(),
-            color_inner: Srgba::hex(CORE_PALETTE[1]).unwrap().into(),
-            color_outer: Srgba::hex(CORE_PALETTE[2]).unwrap().into(),
-            _wasm_padding: Vec2::ZERO,
-        });
+        swallowed.0 += projectile_mass.map_or(0, |mass| mass.0) as f32 * config.growth;
+        let total_mass = config.mass as f32 + swallowed.0;
+        mass.0 = total_mass.round() as u32;
 
-        common = CommonMaterial {
-            pixels: f32::min(bh_radius.0 as f32 / 2., 200.),
-            seed: 69.,
-            ..Default::default()
+        // Keeps the same density as the initial black hole
+        let new_radius = ((config.radius as f32 * (total_mass / config.mass as f32).sqrt()) as u32)
+            .min(config.max_radius);
+        if new_radius != radius.0 {
+            radius.0 = new_radius;
+            *shape = CollisionShape::Circle(new_radius as f32);
         }
-        .scale(CORE_SCALE);
+    }
+}
 
-        common.rotation = RING_ROTATION;
-        common.size = RING_SIZE;
-        common.octaves = RING_OCTAVES;
+fn swallow_players(
+    mut collisions: EventReader<CollisionStarted<Player, BlackHole>>,
+    mut death_events: RollbackEventWriter<DeathEvent>,
+    mut player_query: Query<(&Player, &mut Percentage, Option<&LastHitBy>)>,
+    black_hole_query: Query<Option<&Shooter>, With<BlackHole>>,
+    assets: Res<BlackHoleAssets>,
+    configs: Res<Assets<BlackHoleConfig>>,
+) {
+    let Some(config) = configs.get(&assets.config) else {
+        warn!("Couldn't load BlackHoleConfig");
+        return;
+    };
 
-        let ring = ring_materials.add(BlackHoleRingMaterial {
-            common,
-            disk_width: RING_DISK_WIDTH,
-            ring_perspective: RING_DISK_PERSPECTIVE,
-            should_dither: true as u32,
-            n_colors: 5,
-            colors: [
-                Srgba::hex(RING_PALETTE[0]).unwrap().into(),
-                Srgba::hex(RING_PALETTE[1]).unwrap().into(),
-                Srgba::hex(RING_PALETTE[2]).unwrap().into(),
-                Srgba::hex(RING_PALETTE[3]).unwrap().into(),
-                Srgba::hex(RING_PALETTE[4]).unwrap().into(),
-            ],
-            _wasm_padding: Vec2::ZERO,
-        });
+    for collision in collisions.read() {
+        let Ok((player, mut percentage, last_hit)) = player_query.get_mut(collision.entity) else {
+            continue;
+        };
 
-        let core_entity = commands
-            .spawn((
-                Name::new("BH_CoreMesh"),
-                Mesh2d(meshes.add(Mesh::from(Rectangle::default()))),
-                MeshMaterial2d(core),
-                Transform::from_scale(Vec3::splat(bh_radius.0 as f32 * 2.0)).with_translation(
-                    Vec3 {
-                        x: 0.,
-                        y: 0.,
-                        z: 1.,
-                    },
-                ),
-            ))
-            .id();
-        let ring_entity = commands
-            .spawn((
-                Name::new("BH_RingMesh"),
-                Mesh2d(meshes.add(Mesh::from(Rectangle::default()))),
-                MeshMaterial2d(ring),
-                Transform::from_scale(Vec3::splat((bh_radius.0 as f32 * 2.0) * CORE_SCALE))
-                    .with_translation(Vec3 {
-                        x: 0.,
-                        y: 0.,
-                        z: 2.,
-                    }),
-            ))
-            .id();
-        commands.entity(black_hole).add_children(&[core_entity]);
-        commands.entity(black_hole).add_children(&[ring_entity]);
+        match config.horizon {
+            HorizonEffect::Knockout => {
+                let shooter = black_hole_query.get(collision.other).ok().flatten();
+                death_events.write(DeathEvent {
+                    player: collision.entity,
+                    killer: kills::credited_killer(player, last_hit, shooter),
+                });
+            }
+            HorizonEffect::Damage(damage) => {
+                percentage.0 += damage;
+            }
+        }
     }
 }
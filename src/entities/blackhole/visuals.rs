@@ -1,10 +1,11 @@
 use bevy::{
     prelude::*,
     render::render_resource::{AsBindGroup, ShaderRef},
-    sprite::Material2d,
+    sprite::{Material2d, Material2dPlugin},
 };
 
-use crate::entities::planet::materials::CommonMaterial;
+use super::BlackHole;
+use crate::entities::planet::{Radius, materials::CommonMaterial};
 
 // TODO: move to config
 
@@ -24,6 +25,15 @@ pub const RING_DISK_WIDTH: f32 = 0.065;
 pub const RING_DISK_PERSPECTIVE: f32 = 14.;
 pub static RING_PALETTE: [&str; 5] = ["#000000", "#ffb45c", "#ff8243", "#f25c19", "#fff5cc"];
 
+pub struct BlackHoleVisualsPlugin;
+impl Plugin for BlackHoleVisualsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(Material2dPlugin::<BlackHoleMaterial>::default())
+            .add_plugins(Material2dPlugin::<BlackHoleRingMaterial>::default())
+            .add_systems(Update, (refresh_visuals, add_visuals).chain());
+    }
+}
+
 #[derive(Asset, Reflect, AsBindGroup, Debug, Clone)]
 pub struct BlackHoleMaterial {
     #[uniform(0)]
@@ -84,3 +94,100 @@ impl Material2d for BlackHoleRingMaterial {
         bevy::sprite::AlphaMode2d::Blend
     }
 }
+
+/// Visuals get rebuilt when the black hole grows
+fn refresh_visuals(
+    mut commands: Commands,
+    query: Query<Entity, (With<BlackHole>, With<Children>, Changed<Radius>)>,
+) {
+    for black_hole in query.iter() {
+        commands.entity(black_hole).despawn_related::<Children>();
+    }
+}
+
+fn add_visuals(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut core_materials: ResMut<Assets<BlackHoleMaterial>>,
+    mut ring_materials: ResMut<Assets<BlackHoleRingMaterial>>,
+    query: Query<(Entity, &Radius), (With<BlackHole>, Without<Children>)>,
+) {
+    for (black_hole, bh_radius) in query.iter() {
+        let mut common = CommonMaterial {
+            pixels: f32::min(bh_radius.0 as f32 / 2., 200.),
+            seed: 69.,
+            ..Default::default()
+        };
+
+        // Material spesific
+        common.size = CORE_SIZE;
+        common.octaves = CORE_OCTAVES;
+
+        let core = core_materials.add(BlackHoleMaterial {
+            common,
+            radius: CORE_RADIUS,
+            light_width: CORE_LIGHT_WIDTH,
+            color_core: Srgba::hex(CORE_PALETTE[0]).unwrap().into(),
+            color_inner: Srgba::hex(CORE_PALETTE[1]).unwrap().into(),
+            color_outer: Srgba::hex(CORE_PALETTE[2]).unwrap().into(),
+            _wasm_padding: Vec2::ZERO,
+        });
+
+        common = CommonMaterial {
+            pixels: f32::min(bh_radius.0 as f32 / 2., 200.),
+            seed: 69.,
+            ..Default::default()
+        }
+        .scale(CORE_SCALE);
+
+        common.rotation = RING_ROTATION;
+        common.size = RING_SIZE;
+        common.octaves = RING_OCTAVES;
+
+        let ring = ring_materials.add(BlackHoleRingMaterial {
+            common,
+            disk_width: RING_DISK_WIDTH,
+            ring_perspective: RING_DISK_PERSPECTIVE,
+            should_dither: true as u32,
+            n_colors: 5,
+            colors: [
+                Srgba::hex(RING_PALETTE[0]).unwrap().into(),
+                Srgba::hex(RING_PALETTE[1]).unwrap().into(),
+                Srgba::hex(RING_PALETTE[2]).unwrap().into(),
+                Srgba::hex(RING_PALETTE[3]).unwrap().into(),
+                Srgba::hex(RING_PALETTE[4]).unwrap().into(),
+            ],
+            _wasm_padding: Vec2::ZERO,
+        });
+
+        let core_entity = commands
+            .spawn((
+                Name::new("BH_CoreMesh"),
+                Mesh2d(meshes.add(Mesh::from(Rectangle::default()))),
+                MeshMaterial2d(core),
+                Transform::from_scale(Vec3::splat(bh_radius.0 as f32 * 2.0)).with_translation(
+                    Vec3 {
+                        x: 0.,
+                        y: 0.,
+                        z: 1.,
+                    },
+                ),
+            ))
+            .id();
+        let ring_entity = commands
+            .spawn((
+                Name::new("BH_RingMesh"),
+                Mesh2d(meshes.add(Mesh::from(Rectangle::default()))),
+                MeshMaterial2d(ring),
+                Transform::from_scale(Vec3::splat((bh_radius.0 as f32 * 2.0) * CORE_SCALE))
+                    .with_translation(Vec3 {
+                        x: 0.,
+                        y: 0.,
+                        z: 2.,
+                    }),
+            ))
+            .id();
+        commands.entity(black_hole).add_children(&[core_entity]);
+        commands.entity(black_hole).add_children(&[ring_entity]);
+    }
+}
@@ -1,10 +1,13 @@
 use bevy::prelude::{App, Plugin};
 
 pub mod blackhole;
+pub mod pickup;
 pub mod planet;
 pub mod player;
 pub mod projectile;
 pub mod satellite;
+
+/// Entities simulation plugins
 pub struct EntitiesPlugins;
 impl Plugin for EntitiesPlugins {
     fn build(&self, app: &mut App) {
@@ -12,6 +15,20 @@ impl Plugin for EntitiesPlugins {
             .add_plugins(projectile::ProjectilePlugin)
             .add_plugins(player::PlayerPlugin)
             .add_plugins(satellite::SatellitePlugin)
-            .add_plugins(blackhole::BlackHolePlugin);
+            .add_plugins(blackhole::BlackHolePlugin)
+            .add_plugins(pickup::PickupPlugin);
+    }
+}
+
+/// Entities rendering, animations and sound effects plugins
+pub struct EntitiesVisualsPlugins;
+impl Plugin for EntitiesVisualsPlugins {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(planet::PlanetVisualsPlugin)
+            .add_plugins(projectile::ProjectileVisualsPlugin)
+            .add_plugins(player::PlayerVisualsPlugin)
+            .add_plugins(satellite::SatelliteVisualsPlugin)
+            .add_plugins(blackhole::BlackHoleVisualsPlugin)
+            .add_plugins(pickup::PickupVisualsPlugin);
     }
 }
@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+
+use crate::entities::player::weapon::config::WeaponType;
+
+#[derive(AssetCollection, Resource)]
+pub struct PickupAssets {
+    #[asset(path = "config/config.pickups.ron")]
+    pub config: Handle<PickupConfig>,
+}
+
+#[derive(serde::Deserialize, Asset, TypePath)]
+pub struct PickupConfig {
+    /// Bounds of the random delay in seconds between two crate spawns
+    pub interval: (f32, f32),
+    /// Crates won't spawn while this many are lying around
+    pub max_crates: usize,
+    /// Seconds before an unclaimed crate vanishes
+    pub lifetime: f32,
+    /// Radius within which players can pick the crate up
+    pub radius: f32,
+    /// Height above the planet surface crates spawn at
+    pub altitude: f32,
+    /// Weapons crates can hold, equally likely
+    pub weapons: Vec<WeaponType>,
+}
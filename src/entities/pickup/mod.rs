@@ -0,0 +1,354 @@
+use std::f32::consts::PI;
+
+use bevy::{math::ops, prelude::*};
+use bevy_ggrs::{AddRollbackCommandExtension, GgrsSchedule};
+use rand::{Rng as _, seq::IteratorRandom as _};
+
+use super::planet::{Planet, Radius};
+use super::player::{
+    Player, Weapon,
+    inventory::{self, Arsenal},
+    weapon::{
+        assets::WeaponsAssets,
+        config::{WeaponType, WeaponsConfig, WeaponsConfigAssets},
+    },
+};
+use super::projectile::DecayTimer;
+use crate::{
+    GameState,
+    core::{
+        collision::{CollisionPlugin, CollisionShape, CollisionState},
+        inputs::{PlayerAction, PlayerActionState},
+        physics::{PhysicsSet, Position, Rotation},
+        rng::{RngStream, RollbackRng},
+    },
+};
+
+pub mod config;
+use config::{PickupAssets, PickupConfig};
+
+const CRATE_COLOR: Color = Color::srgb(1., 0.8, 0.3);
+
+type CrateCollision = CollisionState<Player, WeaponCrate>;
+
+/// Holds a weapon, which gets swapped with the current weapon of the player interacting with it
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[require(Visibility, Name::new("WeaponCrate"))]
+pub struct WeaponCrate(pub WeaponType);
+
+/// Counts down to the next crate spawn
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+pub struct CrateSpawner {
+    /// Seconds left, drawn on the first frame and after each spawn
+    pub countdown: Option<f32>,
+}
+
+pub struct PickupPlugin;
+impl Plugin for PickupPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<WeaponCrate>()
+            .register_type::<CrateSpawner>()
+            .add_plugins(CollisionPlugin::<Player, WeaponCrate>::new())
+            .add_systems(OnEnter(GameState::InGame), start_spawner)
+            .add_systems(OnExit(GameState::Results), remove_spawner)
+            .add_systems(
+                GgrsSchedule,
+                (
+                    spawn_crates.before(PhysicsSet::Player).run_if(
+                        resource_exists::<PickupAssets>
+                            .and(resource_exists::<CrateSpawner>)
+                            .and(resource_exists::<RollbackRng>)
+                            .and(any_with_component::<Planet>),
+                    ),
+                    pick_up_crates
+                        .in_set(PhysicsSet::Player)
+                        .before(inventory::handle_slot_change_inputs),
+                )
+                    .run_if(in_state(GameState::InGame)),
+            );
+    }
+}
+
+pub struct PickupVisualsPlugin;
+impl Plugin for PickupVisualsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                add_sprite.run_if(resource_exists::<WeaponsAssets>),
+                draw_crates,
+            ),
+        );
+    }
+}
+
+fn start_spawner(mut commands: Commands) {
+    commands.insert_resource(CrateSpawner::default());
+}
+
+fn remove_spawner(mut commands: Commands) {
+    commands.remove_resource::<CrateSpawner>();
+}
+
+/// Spawns crates above random planets, at random intervals
+fn spawn_crates(
+    mut commands: Commands,
+    mut spawner: ResMut<CrateSpawner>,
+    crate_query: Query<(), With<WeaponCrate>>,
+    planet_query: Query<(&Position, &Radius), With<Planet>>,
+    assets: Res<PickupAssets>,
+    configs: Res<Assets<PickupConfig>>,
+    mut rng: ResMut<RollbackRng>,
+    time: Res<Time>,
+) {
+    let Some(config) = configs.get(&assets.config) else {
+        warn!("Couldn't load PickupConfig");
+        return;
+    };
+
+    let rng = rng.stream(RngStream::Pickups);
+
+    let countdown = spawner.countdown.get_or_insert_with(|| {
+        let (min, max) = config.interval;
+        min + rng.random::<f32>() * (max - min)
+    });
+    *countdown -= time.delta_secs();
+    if *countdown > 0. {
+        return;
+    }
+    spawner.countdown = None;
+
+    if crate_query.iter().count() >= config.max_crates {
+        return;
+    }
+    let Some(weapon) = config.weapons.iter().choose(rng) else {
+        return;
+    };
+    let Some((planet_position, planet_radius)) =
+        planet_query.iter().sort::<&Position>().choose(rng)
+    else {
+        return;
+    };
+
+    let direction = Vec2::from_angle(rng.random::<f32>() * 2. * PI);
+    let position = planet_position.0 + direction * (planet_radius.0 as f32 + config.altitude);
+
+    commands
+        .spawn((
+            WeaponCrate(weapon.clone()),
+            Position(position),
+            // Upright relative to the planet
+            Rotation(ops::atan2(direction.y, direction.x) - PI / 2.),
+            CollisionShape::Circle(config.radius),
+            DecayTimer(Timer::from_seconds(config.lifetime, TimerMode::Once)),
+        ))
+        .add_rollback();
+}
+
+/// Swaps the current weapon of interacting players with the one of the crate they stand in
+fn pick_up_crates(
+    mut commands: Commands,
+    mut player_query: Query<
+        (
+            Entity,
+            &Player,
+            &Weapon,
+            &mut Arsenal,
+            &PlayerActionState,
+            &CrateCollision,
+        ),
+        With<Position>,
+    >,
+    crate_query: Query<&WeaponCrate>,
+) {
+    let mut claimed = Vec::new();
+
+    // Sort by handle for determinism, as several players can claim the same crate
+    for (player_entity, _, current_weapon, mut arsenal, action_state, collision) in
+        player_query.iter_mut().sort::<&Player>()
+    {
+        if !collision.collides || !action_state.just_pressed(&PlayerAction::Interact) {
+            continue;
+        }
+        let Some(crate_entity) = collision
+            .closest
+            .filter(|crate_entity| !claimed.contains(crate_entity))
+        else {
+            continue;
+        };
+        let Ok(weapon_crate) = crate_query.get(crate_entity) else {
+            continue;
+        };
+        let Some(slot) = arsenal
+            .0
+            .iter_mut()
+            .find(|(_, weapon)| *weapon == current_weapon.0)
+        else {
+            warn!("Current weapon is not part of the arsenal");
+            continue;
+        };
+        claimed.push(crate_entity);
+
+        let new_weapon = commands.spawn(weapon_crate.0.clone()).add_rollback().id();
+        // Cannot mutate relationship components so we replace the old one
+        commands.entity(player_entity).insert(Weapon(new_weapon));
+        commands.entity(slot.1).despawn();
+        *slot = (weapon_crate.0.clone(), new_weapon);
+
+        // Might have decayed on the same frame
+        commands.entity(crate_entity).try_despawn();
+    }
+}
+
+fn add_sprite(
+    mut commands: Commands,
+    query: Query<(Entity, &WeaponCrate), Without<Sprite>>,
+    assets: Res<WeaponsAssets>,
+    configs: Res<Assets<WeaponsConfig>>,
+    config_assets: Res<WeaponsConfigAssets>,
+) {
+    let Some(config) = configs.get(&config_assets.config) else {
+        warn!("Couldn't load WeaponsConfig");
+        return;
+    };
+
+    for (crate_entity, weapon_crate) in query.iter() {
+        if let Some(weapon_config) = config.0.get(&weapon_crate.0)
+            && let Some(weapon_assets) = assets.get(&weapon_crate.0)
+        {
+            commands.entity(crate_entity).insert((
+                Sprite::from_image(weapon_assets.skin.clone()),
+                Transform::from_xyz(0.0, 0.0, 10.0)
+                    .with_scale(Vec3::splat(weapon_config.skin.scale)),
+            ));
+        }
+    }
+}
+
+fn draw_crates(
+    mut gizmos: Gizmos,
+    query: Query<(&Position, &Rotation, &CollisionShape), With<WeaponCrate>>,
+) {
+    for (position, rotation, shape) in query.iter() {
+        let size = shape.bounding_radius() * 2.;
+        gizmos.rect_2d(
+            Isometry2d::new(position.0, Rot2::radians(rotation.0)),
+            Vec2::splat(size),
+            CRATE_COLOR,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{platform::collections::HashMap, time::TimeUpdateStrategy};
+    use bevy_ggrs::{
+        GgrsApp as _, GgrsPlugin, LocalInputs, ReadInputs, RollbackFrameCount, Session, ggrs,
+    };
+
+    use super::*;
+    use crate::network::{SessionConfig, inputs::NetworkInputs};
+
+    /// Frame on which the player interacts with the crate
+    const PICKUP_FRAME: i32 = 5;
+    /// Frames re-simulated on every frame, so that rollbacks cross the pickup
+    const CHECK_DISTANCE: usize = 2;
+
+    fn read_inputs(mut commands: Commands) {
+        commands.insert_resource(LocalInputs::<SessionConfig>(HashMap::from([(
+            0,
+            NetworkInputs::default(),
+        )])));
+    }
+
+    /// Interacts on `PICKUP_FRAME` only, re-simulated frames included
+    fn interact(mut query: Query<&mut PlayerActionState>, frame: Res<RollbackFrameCount>) {
+        for mut action_state in query.iter_mut() {
+            action_state.reset(&PlayerAction::Interact);
+            if frame.0 == PICKUP_FRAME {
+                action_state.press(&PlayerAction::Interact);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rollback_across_pickup() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, GgrsPlugin::<SessionConfig>::default()))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+                1. / 60.,
+            )))
+            .rollback_component_with_clone::<Arsenal>()
+            .update_component_with_map_entities::<Arsenal>()
+            .rollback_immutable_component_with_clone::<Weapon>()
+            .rollback_component_with_clone::<WeaponType>()
+            .rollback_component_with_clone::<WeaponCrate>()
+            .rollback_component_with_clone::<CrateCollision>()
+            .update_component_with_map_entities::<CrateCollision>()
+            .add_systems(ReadInputs, read_inputs)
+            .add_systems(GgrsSchedule, (interact, pick_up_crates).chain());
+
+        let session = ggrs::SessionBuilder::<SessionConfig>::new()
+            .with_num_players(1)
+            .with_check_distance(CHECK_DISTANCE)
+            .add_player(ggrs::PlayerType::Local, 0)
+            .expect("Should add player")
+            .start_synctest_session()
+            .expect("Should start session");
+        app.insert_resource(Session::SyncTest(session));
+
+        let mut commands = app.world_mut().commands();
+        let weapons = [WeaponType::Pistol, WeaponType::Shotgun].map(|weapon_type| {
+            let weapon = commands.spawn(weapon_type.clone()).add_rollback().id();
+            (weapon_type, weapon)
+        });
+        let weapon_crate = commands
+            .spawn(WeaponCrate(WeaponType::Sniper))
+            .add_rollback()
+            .id();
+        let mut collision = CrateCollision::default();
+        collision.collides = true;
+        collision.closest = Some(weapon_crate);
+        let player = commands
+            .spawn((
+                Player { handle: 0 },
+                Position::default(),
+                PlayerActionState::default(),
+                collision,
+                Weapon(weapons[0].1),
+                Arsenal(weapons.to_vec()),
+            ))
+            .add_rollback()
+            .id();
+        app.world_mut().flush();
+
+        for _ in 0..100 {
+            if app.world().resource::<RollbackFrameCount>().0 > PICKUP_FRAME + 10 {
+                break;
+            }
+            app.update();
+        }
+        assert!(app.world().resource::<RollbackFrameCount>().0 > PICKUP_FRAME + 10);
+
+        let world = app.world_mut();
+        let arsenal = world
+            .get::<Arsenal>(player)
+            .expect("Should keep an arsenal");
+        assert_eq!(
+            arsenal
+                .0
+                .iter()
+                .map(|(weapon_type, _)| weapon_type.clone())
+                .collect::<Vec<_>>(),
+            vec![WeaponType::Sniper, WeaponType::Shotgun]
+        );
+        for (weapon_type, weapon) in arsenal.0.iter() {
+            assert_eq!(world.get::<WeaponType>(*weapon), Some(weapon_type));
+        }
+        // Neither the swapped weapon nor the crate linger
+        assert_eq!(world.query::<&WeaponType>().iter(world).count(), 2);
+        assert_eq!(world.query::<&WeaponCrate>().iter(world).count(), 0);
+    }
+}
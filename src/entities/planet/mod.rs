@@ -74,12 +74,18 @@ impl Plugin for PlanetPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<Radius>()
             .register_type::<PlanetType>()
-            .add_plugins(materials::PlanetMaterialsPlugin)
             .add_event::<SpawnPlanetEvent>()
+            .add_systems(Update, handle_spawn_planet_event);
+    }
+}
+
+pub struct PlanetVisualsPlugin;
+impl Plugin for PlanetVisualsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(materials::PlanetMaterialsPlugin)
             .add_systems(
                 Update,
                 (
-                    handle_spawn_planet_event,
                     spawn_config_layers.run_if(resource_exists::<PlanetAssets>),
                     #[cfg(feature = "dev_tools")]
                     handle_config_reload,
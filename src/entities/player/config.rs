@@ -0,0 +1,49 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_asset_loader::asset_collection::AssetCollection;
+
+#[derive(AssetCollection, Resource)]
+pub struct PlayerAssets {
+    #[asset(path = "config/config.player.ron")]
+    pub config: Handle<PlayerConfig>,
+}
+
+#[derive(serde::Deserialize, Asset, TypePath)]
+pub struct PlayerConfig {
+    /// Stats of players whose skin has no override
+    pub default: PlayerStats,
+    /// Per-skin stats, keyed by skin name
+    #[serde(default)]
+    pub skins: HashMap<String, PlayerStats>,
+}
+
+#[derive(Component, Clone, Debug, Reflect, serde::Deserialize)]
+/// Player movement and physics tuning
+pub struct PlayerStats {
+    pub mass: u32,
+    pub radius: f32,
+    /// Max walking and sneaking speed
+    pub speed: f32,
+    pub jump_velocity: f32,
+    /// Velocity multiplier applied when on ground
+    pub ground_friction: f32,
+    /// Max angle in degrees between player and ground normal to land instead of bouncing
+    pub landing_angle: f32,
+    /// Lerp rate at which the player rotates towards the closest planet
+    pub rotation_rate: f32,
+    /// Lerp rate towards walking speed
+    pub acceleration_rate: f32,
+    /// Lerp rate towards zero when not walking
+    pub deceleration_rate: f32,
+    /// Lerp rate towards zero of the walking velocity while airborne
+    pub air_deceleration_rate: f32,
+    /// Lerp rate towards sneaking speed
+    pub sneak_rate: f32,
+    /// Lerp rate towards zero when not sneaking
+    pub sneak_release_rate: f32,
+}
+
+impl PlayerConfig {
+    pub fn stats(&self, skin: &str) -> &PlayerStats {
+        self.skins.get(skin).unwrap_or(&self.default)
+    }
+}
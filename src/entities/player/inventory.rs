@@ -1,8 +1,11 @@
-use bevy::prelude::*;
-use bevy_ggrs::{AddRollbackCommandExtension, GgrsSchedule};
+use bevy::{
+    ecs::entity::{EntityMapper, MapEntities},
+    prelude::*,
+};
+use bevy_ggrs::{AddRollbackCommandExtension, GgrsSchedule, ReadInputs};
 use leafwing_input_manager::prelude::ActionState;
 
-use crate::core::physics;
+use crate::core::{inputs::InputSet, physics};
 
 use super::{Player, PlayerAction, Weapon, weapon::config::WeaponType};
 
@@ -12,25 +15,40 @@ const DEFAULT_ARSENAL: [WeaponType; 3] = [
     WeaponType::Rifle,
 ];
 
-#[derive(Component, Debug, Reflect)]
+/// Weapons of a player, by slot. Remapped on rollback, as restored weapons get new ids.
+#[derive(Component, Clone, Debug, Reflect)]
 pub struct Arsenal(pub Vec<(WeaponType, Entity)>);
 
+impl MapEntities for Arsenal {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        for (_, weapon) in self.0.iter_mut() {
+            *weapon = entity_mapper.get_mapped(*weapon);
+        }
+    }
+}
+
 pub struct InventoryPlugin;
 impl Plugin for InventoryPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<Arsenal>()
-            .add_systems(Update, (spawn_arsenal, update_slot_inputs))
+            .add_systems(ReadInputs, update_slot_inputs.in_set(InputSet::Update))
             .add_systems(
                 GgrsSchedule,
-                (handle_slot_change_inputs, summon_current_weapon)
-                    .chain()
-                    .in_set(physics::PhysicsSet::Player)
-                    .before(super::update_weapon),
+                (
+                    spawn_arsenal.before(physics::PhysicsSet::Player),
+                    (handle_slot_change_inputs, summon_current_weapon)
+                        .chain()
+                        .in_set(physics::PhysicsSet::Player)
+                        .before(super::update_weapon),
+                ),
             );
     }
 }
 
-fn spawn_arsenal(mut commands: Commands, query: Query<(Entity, &Player), Without<Arsenal>>) {
+pub(super) fn spawn_arsenal(
+    mut commands: Commands,
+    query: Query<(Entity, &Player), Without<Arsenal>>,
+) {
     for (player_entity, _) in query
         .iter()
         //Sort by handle for determinism
@@ -59,9 +77,10 @@ fn spawn_arsenal(mut commands: Commands, query: Query<(Entity, &Player), Without
     }
 }
 
+/// Equips current weapons that aren't yet. Doesn't rely on change detection, which doesn't survive rollbacks
 fn summon_current_weapon(
     mut commands: Commands,
-    owner_query: Query<&Weapon, Changed<Weapon>>,
+    owner_query: Query<&Weapon>,
     weapon_query: Query<Entity, Without<physics::Position>>,
 ) {
     for weapon_ref in owner_query.iter() {
@@ -74,7 +93,7 @@ fn summon_current_weapon(
     }
 }
 
-fn handle_slot_change_inputs(
+pub(crate) fn handle_slot_change_inputs(
     mut commands: Commands,
     query: Query<(Entity, &Weapon, &Arsenal, &ActionState<PlayerAction>)>,
 ) {
@@ -3,7 +3,7 @@ use std::f32::consts::PI;
 use bevy::prelude::*;
 use bevy_ggrs::GgrsSchedule;
 use leafwing_input_manager::prelude::*;
-use weapon::{WeaponMode, WeaponState};
+use weapon::{WeaponEvent, WeaponMode, WeaponState};
 
 use crate::core::collision::{CollisionPlugin, CollisionShape, CollisionState};
 use crate::core::gravity::{Mass, Passive};
@@ -14,18 +14,19 @@ use crate::utils::math;
 
 use super::planet;
 use crate::entities::satellite::slingshot::Orbited;
+use crate::network::events::RollbackEventWriter;
 
 mod animation;
+pub mod config;
 pub mod inventory;
 pub mod skin;
+pub mod status;
+pub mod trajectory;
 pub mod weapon;
 
-// TODO: Move to config file
-pub const PLAYER_MASS: u32 = 800;
-pub const PLAYER_VELOCITY: f32 = 600.;
-pub const PLAYER_JUMP_VELOCITY: f32 = 800.;
-pub const PLAYER_RADIUS: f32 = 16. * 2.;
-const PLAYER_GROUND_FRICTION_COEFF: f32 = 0.95;
+pub use config::PlayerStats;
+use config::{PlayerAssets, PlayerConfig};
+use status::StatusEffects;
 
 type PlanetCollision = CollisionState<Player, planet::Planet>;
 
@@ -37,11 +38,10 @@ type PlanetCollision = CollisionState<Player, planet::Planet>;
     PlayerInputVelocity,
     Passive,
     ActionState<PlayerAction>,
-    Mass(PLAYER_MASS),
-    CollisionShape::Circle(PLAYER_RADIUS),
     PlayerSkin("laika.skin".into()),
     Name::new("Player"),
     Percentage::default(),
+    StatusEffects,
 )]
 pub struct Player {
     pub handle: usize,
@@ -56,9 +56,6 @@ pub struct PlayerInputVelocity(Vec2);
 #[derive(Component, Clone, Debug, PartialEq, Reflect)]
 pub struct PlayerSkin(pub String);
 
-#[derive(Component, Clone, Debug, Reflect)]
-pub struct Stunned;
-
 #[derive(Component, Clone, Debug, PartialEq, Reflect)]
 #[relationship(relationship_target = weapon::Owner)]
 pub struct Weapon(pub Entity);
@@ -70,24 +67,65 @@ impl Plugin for PlayerPlugin {
             .register_type::<Player>()
             .register_type::<PlayerInputVelocity>()
             .register_type::<PlayerSkin>()
-            .register_type::<Stunned>()
+            .register_type::<StatusEffects>()
+            .register_type::<status::Inflicts>()
             .register_type::<Weapon>()
+            .register_type::<PlayerStats>()
             .add_plugins(CollisionPlugin::<Player, planet::Planet>::new())
-            .add_plugins(animation::PlayerAnimationPlugin)
             .add_plugins(inventory::InventoryPlugin)
-            .add_plugins(skin::SkinPlugin)
             .add_plugins(weapon::WeaponPlugin)
+            .add_systems(
+                GgrsSchedule,
+                add_stats
+                    .before(PhysicsSet::Player)
+                    .run_if(resource_exists::<PlayerAssets>),
+            )
             .add_systems(
                 GgrsSchedule,
                 (
                     player_physics,
                     player_movement,
                     update_weapon,
-                    reset_stunned,
+                    status::tick_status_effects,
                 )
                     .chain()
                     .in_set(PhysicsSet::Player),
             );
+
+        #[cfg(feature = "dev_tools")]
+        app.add_systems(Update, handle_config_reload);
+    }
+}
+
+pub struct PlayerVisualsPlugin;
+impl Plugin for PlayerVisualsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(animation::PlayerAnimationPlugin)
+            .add_plugins(skin::SkinPlugin)
+            .add_plugins(trajectory::TrajectoryPreviewPlugin)
+            .add_plugins(weapon::WeaponVisualsPlugin);
+    }
+}
+
+/// Inserts the stats matching the player skin, along with the physical properties derived from them
+pub(crate) fn add_stats(
+    mut commands: Commands,
+    query: Query<(Entity, &PlayerSkin), (With<Player>, Without<PlayerStats>)>,
+    assets: Res<PlayerAssets>,
+    configs: Res<Assets<PlayerConfig>>,
+) {
+    let Some(config) = configs.get(&assets.config) else {
+        warn!("Couldn't load PlayerConfig");
+        return;
+    };
+
+    for (player_entity, skin) in query.iter() {
+        let stats = config.stats(&skin.0);
+        commands.entity(player_entity).insert((
+            Mass(stats.mass),
+            CollisionShape::Circle(stats.radius),
+            stats.clone(),
+        ));
     }
 }
 
@@ -99,6 +137,8 @@ fn player_movement(
             &mut PlayerInputVelocity,
             &Rotation,
             &PlanetCollision,
+            &PlayerStats,
+            &StatusEffects,
         ),
         (With<Player>, Without<Orbited>),
     >,
@@ -106,39 +146,59 @@ fn player_movement(
 ) {
     let delta = time.delta_secs();
 
-    for (action_state, mut velocity, mut input_velocity, rotation, collision) in query.iter_mut() {
-        if !collision.collides {
-            input_velocity.0 = input_velocity.lerp(Vec2::ZERO, delta * 6.);
+    for (action_state, mut velocity, mut input_velocity, rotation, collision, stats, effects) in
+        query.iter_mut()
+    {
+        if !collision.collides || effects.is_stunned() || effects.is_frozen() {
+            input_velocity.0 = input_velocity.lerp(Vec2::ZERO, delta * stats.air_deceleration_rate);
             continue;
         }
 
+        let speed_multiplier = effects.speed_multiplier();
+        let speed = stats.speed * speed_multiplier;
+
         if action_state.pressed(&PlayerAction::Jump) {
-            velocity.0 = Vec2::from_angle(rotation.0).rotate(Vec2::Y) * PLAYER_JUMP_VELOCITY;
+            velocity.0 = Vec2::from_angle(rotation.0).rotate(Vec2::Y)
+                * stats.jump_velocity
+                * speed_multiplier;
         }
 
         if action_state.pressed(&PlayerAction::Right) {
-            input_velocity.0.x = math::lerp(input_velocity.0.x, PLAYER_VELOCITY, delta * 2.);
+            input_velocity.0.x =
+                math::lerp(input_velocity.0.x, speed, delta * stats.acceleration_rate);
         }
         if action_state.pressed(&PlayerAction::Left) {
-            input_velocity.0.x = math::lerp(input_velocity.0.x, -PLAYER_VELOCITY, delta * 2.);
+            input_velocity.0.x =
+                math::lerp(input_velocity.0.x, -speed, delta * stats.acceleration_rate);
         }
 
         if !(action_state.pressed(&PlayerAction::Right)
             || action_state.pressed(&PlayerAction::Left))
         {
-            input_velocity.0.x = math::lerp(input_velocity.0.x, 0., delta * 6.0);
+            input_velocity.0.x =
+                math::lerp(input_velocity.0.x, 0., delta * stats.deceleration_rate);
         }
 
         if action_state.pressed(&PlayerAction::Sneak) {
-            input_velocity.0.y = math::lerp(input_velocity.0.y, -PLAYER_VELOCITY, delta);
+            input_velocity.0.y = math::lerp(input_velocity.0.y, -speed, delta * stats.sneak_rate);
         } else {
-            input_velocity.0.y = math::lerp(input_velocity.0.y, 0., delta * 10.);
+            input_velocity.0.y =
+                math::lerp(input_velocity.0.y, 0., delta * stats.sneak_release_rate);
         }
     }
 }
 
 fn update_weapon(
-    player_query: Query<(&ActionState<PlayerAction>, &Position, &Velocity, &Weapon), With<Player>>,
+    player_query: Query<
+        (
+            &ActionState<PlayerAction>,
+            &Position,
+            &Velocity,
+            &Weapon,
+            &StatusEffects,
+        ),
+        With<Player>,
+    >,
     mut weapon_query: Query<
         (
             &mut weapon::WeaponMode,
@@ -150,8 +210,9 @@ fn update_weapon(
         ),
         Without<Player>,
     >,
+    mut events: RollbackEventWriter<WeaponEvent>,
 ) {
-    for (action_state, player_position, player_velocity, weapon) in player_query.iter() {
+    for (action_state, player_position, player_velocity, weapon, effects) in player_query.iter() {
         let axis_pair = action_state.axis_pair(&PlayerAction::PointerDirection);
         if let Ok((
             mut mode,
@@ -167,7 +228,11 @@ fn update_weapon(
             } else {
                 0.0
             };
-            let pressed = action_state.get_pressed();
+            let pressed = if effects.is_frozen() {
+                Vec::new()
+            } else {
+                action_state.get_pressed()
+            };
             if pressed.contains(&PlayerAction::Shoot) && weapon_state.current_ammo > 0 {
                 *mode = WeaponMode::Triggered;
             } else if pressed.contains(&PlayerAction::Reload)
@@ -175,6 +240,7 @@ fn update_weapon(
                 && *mode != WeaponMode::Reloading
             {
                 *mode = WeaponMode::Reloading;
+                events.write(WeaponEvent::ReloadStart(weapon.0));
             } else if *mode != WeaponMode::Reloading {
                 *mode = WeaponMode::Idle;
             }
@@ -194,7 +260,8 @@ fn player_physics(
             &mut Velocity,
             &PlanetCollision,
             &PlayerInputVelocity,
-            Has<Stunned>,
+            &PlayerStats,
+            &StatusEffects,
         ),
         (With<Player>, Without<planet::Planet>, Without<Orbited>),
     >,
@@ -207,7 +274,8 @@ fn player_physics(
         mut velocity,
         planet_collision,
         input_velocity,
-        is_stunned,
+        stats,
+        effects,
     ) in player_query.iter_mut()
     {
         // Find nearest planet (asserts that one planet exists)
@@ -224,7 +292,7 @@ fn player_physics(
         ) + PI / 2.;
         let mut short_angle = (target_angle - player_rotation.0) % math::RAD;
         short_angle = (2. * short_angle) % math::RAD - short_angle;
-        player_rotation.0 += short_angle * time.delta_secs() * 6.;
+        player_rotation.0 += short_angle * time.delta_secs() * stats.rotation_rate;
 
         player_position.0 +=
             Vec2::from_angle(player_rotation.0).rotate(input_velocity.0) * time.delta_secs();
@@ -235,12 +303,12 @@ fn player_physics(
             let collision_normal = (player_position.0 - nearest_planet_pos.0).normalize();
             // Clip player to ground
             let clip_position = nearest_planet_pos.0
-                + collision_normal * (PLAYER_RADIUS + nearest_planet_shape.bounding_radius());
+                + collision_normal * (stats.radius + nearest_planet_shape.bounding_radius());
             player_position.0 = clip_position;
 
             // Bounce if not on feet or stunned
             let rotation_diff = math::clip_angle(player_rotation.0 - target_angle);
-            if is_stunned || rotation_diff.abs() > 30f32.to_radians() {
+            if effects.is_stunned() || rotation_diff.abs() > stats.landing_angle.to_radians() {
                 let velocity_along_normal = velocity.0.dot(collision_normal);
                 let reflexion_vector = velocity.0 - 2. * velocity_along_normal * collision_normal;
                 velocity.0 = reflexion_vector * 0.5;
@@ -249,17 +317,24 @@ fn player_physics(
                 let dot_product = velocity.dot(collision_normal);
                 velocity.0 -= dot_product * collision_normal;
                 // Apply ground friction
-                velocity.0 *= PLAYER_GROUND_FRICTION_COEFF * time.delta_secs();
+                velocity.0 *= stats.ground_friction * time.delta_secs();
             }
         }
     }
 }
 
-/// Temporary way of dealing with the `Stunned` state, which for now is only used by bullets
-/// in order to bounce during a frame in which the player has been hit.
-// TODO: Make less cringe (gameplay design required)
-fn reset_stunned(mut commands: Commands, query: Query<Entity, (With<Player>, With<Stunned>)>) {
-    for player in query.iter() {
-        commands.entity(player).remove::<Stunned>();
+/// Re-applies stats on config changes. Will cause desyncs
+#[cfg(feature = "dev_tools")]
+fn handle_config_reload(
+    mut commands: Commands,
+    mut events: EventReader<AssetEvent<PlayerConfig>>,
+    players: Query<Entity, (With<Player>, With<PlayerStats>)>,
+) {
+    for event in events.read() {
+        if let AssetEvent::Modified { id: _ } = event {
+            for player in players.iter() {
+                commands.entity(player).remove::<PlayerStats>();
+            }
+        };
     }
 }
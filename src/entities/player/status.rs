@@ -0,0 +1,244 @@
+use std::mem::discriminant;
+
+use bevy::prelude::*;
+
+use super::Percentage;
+
+/// Temporary alteration of a player's state
+#[derive(serde::Deserialize, Clone, Copy, Debug, PartialEq, Reflect)]
+pub enum StatusEffect {
+    /// Bounces off planets instead of landing, and ignores movement inputs
+    Stun,
+    /// Multiplies movement speed and jump velocity
+    Slow(f32),
+    /// Ignores every input
+    Freeze,
+    /// Percentage dealt per second
+    Burn(f32),
+    /// Fraction of the received knockback that gets ignored
+    KnockbackResistance(f32),
+}
+
+/// What happens when an effect of the same kind is already active
+#[derive(serde::Deserialize, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub enum Stacking {
+    /// Replaces the active effect and restarts its duration
+    #[default]
+    Refresh,
+    /// Adds the duration to the remaining one, keeping the active effect strength
+    Extend,
+    /// Runs alongside the active effect, so that their strengths add up
+    Stack,
+}
+
+#[derive(serde::Deserialize, Clone, Copy, Debug, PartialEq, Reflect)]
+pub struct StatusEffectConfig {
+    pub effect: StatusEffect,
+    /// Seconds during which the effect is active. Zero lasts for a single frame
+    pub duration: f32,
+    #[serde(default)]
+    pub stacking: Stacking,
+}
+
+impl StatusEffectConfig {
+    /// Lets players hit while on the ground bounce off instead of staying stuck
+    pub const HIT_STUN: Self = Self {
+        effect: StatusEffect::Stun,
+        duration: 0.,
+        stacking: Stacking::Refresh,
+    };
+}
+
+/// Effects inflicted to the players hit by a projectile
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+pub struct Inflicts(pub Vec<StatusEffectConfig>);
+
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct ActiveEffect {
+    pub effect: StatusEffect,
+    pub timer: Timer,
+}
+
+/// Status effects currently affecting a player, in application order
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+pub struct StatusEffects(pub Vec<ActiveEffect>);
+
+impl StatusEffects {
+    pub fn apply(&mut self, config: &StatusEffectConfig) {
+        let active = self
+            .0
+            .iter_mut()
+            .find(|active| discriminant(&active.effect) == discriminant(&config.effect));
+
+        match (config.stacking, active) {
+            (Stacking::Refresh, Some(active)) => {
+                active.effect = config.effect;
+                active.timer = Timer::from_seconds(config.duration, TimerMode::Once);
+            }
+            (Stacking::Extend, Some(active)) => {
+                let duration = active.timer.duration();
+                active
+                    .timer
+                    .set_duration(duration + std::time::Duration::from_secs_f32(config.duration));
+            }
+            _ => self.0.push(ActiveEffect {
+                effect: config.effect,
+                timer: Timer::from_seconds(config.duration, TimerMode::Once),
+            }),
+        }
+    }
+
+    pub fn apply_all<'a>(&mut self, configs: impl IntoIterator<Item = &'a StatusEffectConfig>) {
+        for config in configs {
+            self.apply(config);
+        }
+    }
+
+    fn effects(&self) -> impl Iterator<Item = &StatusEffect> {
+        self.0.iter().map(|active| &active.effect)
+    }
+
+    pub fn is_stunned(&self) -> bool {
+        self.effects().any(|effect| *effect == StatusEffect::Stun)
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.effects().any(|effect| *effect == StatusEffect::Freeze)
+    }
+
+    /// Multiplier applied to movement speed and jump velocity
+    pub fn speed_multiplier(&self) -> f32 {
+        self.effects()
+            .map(|effect| match effect {
+                StatusEffect::Slow(factor) => *factor,
+                _ => 1.,
+            })
+            .product()
+    }
+
+    /// Multiplier applied to received knockback
+    pub fn knockback_multiplier(&self) -> f32 {
+        self.effects()
+            .map(|effect| match effect {
+                StatusEffect::KnockbackResistance(resistance) => 1. - resistance.clamp(0., 1.),
+                _ => 1.,
+            })
+            .product()
+    }
+
+    /// Percentage dealt per second
+    pub fn burn_rate(&self) -> f32 {
+        self.effects()
+            .map(|effect| match effect {
+                StatusEffect::Burn(rate) => *rate,
+                _ => 0.,
+            })
+            .sum()
+    }
+}
+
+/// Applies tick effects, then removes expired ones.
+/// Runs at the end of `PhysicsSet::Player`, right after `player_movement` and `update_weapon`
+/// but before `PhysicsSet::Movement`, so effects applied later in a frame are seen on the next one.
+pub(super) fn tick_status_effects(
+    mut query: Query<(&mut StatusEffects, &mut Percentage)>,
+    time: Res<Time>,
+) {
+    for (mut effects, mut percentage) in query.iter_mut() {
+        if effects.0.is_empty() {
+            continue;
+        }
+
+        percentage.0 += effects.burn_rate() * time.delta_secs();
+
+        for active in effects.0.iter_mut() {
+            active.timer.tick(time.delta());
+        }
+        effects.0.retain(|active| !active.timer.finished());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f32 = 1e-4;
+
+    fn config(effect: StatusEffect, duration: f32, stacking: Stacking) -> StatusEffectConfig {
+        StatusEffectConfig {
+            effect,
+            duration,
+            stacking,
+        }
+    }
+
+    fn remaining(active: &ActiveEffect) -> f32 {
+        active.timer.remaining_secs()
+    }
+
+    #[test]
+    fn test_refresh_replaces_active_effect() {
+        let mut effects = StatusEffects::default();
+        effects.apply(&config(StatusEffect::Slow(0.5), 2., Stacking::Refresh));
+        effects.0[0]
+            .timer
+            .tick(std::time::Duration::from_secs_f32(1.5));
+
+        effects.apply(&config(StatusEffect::Slow(0.8), 1., Stacking::Refresh));
+
+        assert_eq!(effects.0.len(), 1);
+        assert_eq!(effects.0[0].effect, StatusEffect::Slow(0.8));
+        assert!((remaining(&effects.0[0]) - 1.).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_extend_adds_duration_and_keeps_strength() {
+        let mut effects = StatusEffects::default();
+        effects.apply(&config(StatusEffect::Slow(0.5), 2., Stacking::Extend));
+        effects.0[0]
+            .timer
+            .tick(std::time::Duration::from_secs_f32(0.5));
+
+        effects.apply(&config(StatusEffect::Slow(0.8), 1.5, Stacking::Extend));
+
+        assert_eq!(effects.0.len(), 1);
+        assert_eq!(effects.0[0].effect, StatusEffect::Slow(0.5));
+        assert!((remaining(&effects.0[0]) - 3.).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_stack_adds_up_strengths() {
+        let mut effects = StatusEffects::default();
+        effects.apply(&config(StatusEffect::Burn(0.1), 1., Stacking::Stack));
+        effects.apply(&config(StatusEffect::Burn(0.2), 2., Stacking::Stack));
+
+        assert_eq!(effects.0.len(), 2);
+        assert!((effects.burn_rate() - 0.3).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_different_effects_dont_interact() {
+        let mut effects = StatusEffects::default();
+        effects.apply(&config(StatusEffect::Slow(0.5), 1., Stacking::Refresh));
+        effects.apply(&config(StatusEffect::Freeze, 1., Stacking::Refresh));
+
+        assert_eq!(effects.0.len(), 2);
+        assert!(effects.is_frozen());
+        assert!((effects.speed_multiplier() - 0.5).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_hit_stun_lasts_a_single_frame() {
+        let mut effects = StatusEffects::default();
+        effects.apply(&StatusEffectConfig::HIT_STUN);
+        assert!(effects.is_stunned());
+
+        for active in effects.0.iter_mut() {
+            active
+                .timer
+                .tick(std::time::Duration::from_secs_f32(1. / 60.));
+        }
+        effects.0.retain(|active| !active.timer.finished());
+        assert!(!effects.is_stunned());
+    }
+}
@@ -0,0 +1,254 @@
+use bevy::{math::FloatPow as _, prelude::*};
+use bevy_ggrs::{LocalPlayers, RollbackFrameRate};
+
+use super::{Player, PlayerStats, Weapon, weapon::config::WeaponStats};
+use crate::{
+    GameState,
+    core::{
+        collision::CollisionShape,
+        gravity::{GravityAssets, GravityField, GravityReceiverScale, GravitySources},
+        inputs::{PlayerAction, PlayerActionState},
+        physics::{Integration, Position, Rotation, Velocity},
+    },
+    entities::{
+        planet::Planet,
+        projectile::config::{ProjectilesAssets, ProjectilesConfig},
+    },
+};
+
+const PLAYER_DOT_COLOR: Color = Color::srgba(1., 1., 1., 0.6);
+const PROJECTILE_DOT_COLOR: Color = Color::srgba(1., 0.4, 0.3, 0.6);
+const DOT_SIZE: f32 = 4.;
+
+/// Settings of the local player trajectory preview
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct TrajectoryPreview {
+    pub enabled: bool,
+    /// How far in the future the trajectory is predicted, in seconds
+    pub duration: f32,
+    /// Amount of dots drawn along each path
+    pub dots: usize,
+    /// Also previews the path of the next fired projectile
+    pub show_projectile: bool,
+}
+
+#[derive(Component)]
+#[require(Name::new("TrajectoryDot"))]
+struct TrajectoryDot {
+    path: TrajectoryPath,
+    index: usize,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TrajectoryPath {
+    Player,
+    Projectile,
+}
+
+/// Draws the predicted paths of the local player from `Update`, so that it never affects the rollback state.
+pub struct TrajectoryPreviewPlugin;
+impl Plugin for TrajectoryPreviewPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<TrajectoryPreview>()
+            .init_resource::<TrajectoryPreview>()
+            .add_systems(OnEnter(GameState::InGame), spawn_dots)
+            .add_systems(OnExit(GameState::InGame), despawn_dots)
+            .add_systems(
+                Update,
+                (toggle_preview, update_dots)
+                    .chain()
+                    .run_if(in_state(GameState::InGame).and(resource_exists::<GravityAssets>)),
+            );
+    }
+}
+
+fn spawn_dots(mut commands: Commands, preview: Res<TrajectoryPreview>) {
+    for path in [TrajectoryPath::Player, TrajectoryPath::Projectile] {
+        let color = match path {
+            TrajectoryPath::Player => PLAYER_DOT_COLOR,
+            TrajectoryPath::Projectile => PROJECTILE_DOT_COLOR,
+        };
+
+        for index in 0..preview.dots {
+            commands.spawn((
+                TrajectoryDot { path, index },
+                Sprite::from_color(color, Vec2::splat(DOT_SIZE)),
+                Transform::from_xyz(0., 0., 5.),
+                Visibility::Hidden,
+            ));
+        }
+    }
+}
+
+fn despawn_dots(mut commands: Commands, query: Query<Entity, With<TrajectoryDot>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn toggle_preview(
+    players: Query<(&Player, &PlayerActionState)>,
+    local_players: Option<Res<LocalPlayers>>,
+    mut preview: ResMut<TrajectoryPreview>,
+) {
+    let Some(local_players) = local_players else {
+        return;
+    };
+
+    if players.iter().any(|(player, action_state)| {
+        local_players.0.contains(&player.handle)
+            && action_state.just_pressed(&PlayerAction::TogglePreview)
+    }) {
+        preview.enabled = !preview.enabled;
+    }
+}
+
+fn update_dots(
+    mut dots: Query<(&TrajectoryDot, &mut Transform, &mut Visibility)>,
+    players: Query<(
+        &Player,
+        &Position,
+        &Velocity,
+        &PlayerStats,
+        Option<&GravityReceiverScale>,
+        Option<&Weapon>,
+    )>,
+    weapons: Query<(&Position, &Rotation, &WeaponStats)>,
+    planets: Query<(&Position, &CollisionShape), With<Planet>>,
+    sources: GravitySources,
+    preview: Res<TrajectoryPreview>,
+    integration: Res<Integration>,
+    frame_rate: Option<Res<RollbackFrameRate>>,
+    local_players: Option<Res<LocalPlayers>>,
+    projectiles_assets: Option<Res<ProjectilesAssets>>,
+    projectiles_configs: Res<Assets<ProjectilesConfig>>,
+) {
+    let local_player = local_players.and_then(|local_players| {
+        players
+            .iter()
+            .find(|(player, ..)| local_players.0.contains(&player.handle))
+    });
+
+    let paths = match (preview.enabled, local_player, sources.field()) {
+        (true, Some((_, position, velocity, stats, scale, weapon)), Some(field)) => {
+            let delta = 1. / frame_rate.map_or(60, |frame_rate| frame_rate.0) as f32;
+            let steps = (preview.duration / delta) as usize;
+            let predictor = Predictor {
+                field,
+                planets: planets
+                    .iter()
+                    .map(|(position, shape)| (position.0, shape.bounding_radius()))
+                    .collect(),
+                integration: integration.clone(),
+                delta,
+                steps_per_dot: (steps / preview.dots.max(1)).max(1),
+            };
+
+            let player_path = predictor.predict(
+                position.0,
+                velocity.0,
+                scale.map_or(1., |scale| scale.0),
+                stats.radius,
+                preview.dots,
+            );
+
+            let projectile_path = weapon
+                .filter(|_| preview.show_projectile)
+                .and_then(|weapon| weapons.get(weapon.0).ok())
+                .map(|(weapon_position, rotation, stats)| {
+                    let gravity_scale = projectiles_assets
+                        .as_ref()
+                        .and_then(|assets| projectiles_configs.get(&assets.config))
+                        .and_then(|config| config.0.get(&stats.projectile))
+                        .map_or(1., |config| config.stats.gravity_scale);
+
+                    // Same as when firing, without spread
+                    let direction = Vec2::from_angle(rotation.0);
+                    let added_velocity = velocity.0.dot(direction);
+                    predictor.predict(
+                        weapon_position.0 + direction * stats.radius,
+                        direction * (stats.projectile_speed + added_velocity),
+                        gravity_scale,
+                        0.,
+                        preview.dots,
+                    )
+                })
+                .unwrap_or_default();
+
+            (player_path, projectile_path)
+        }
+        _ => Default::default(),
+    };
+
+    for (dot, mut transform, mut visibility) in dots.iter_mut() {
+        let path = match dot.path {
+            TrajectoryPath::Player => &paths.0,
+            TrajectoryPath::Projectile => &paths.1,
+        };
+
+        if let Some(point) = path.get(dot.index) {
+            transform.translation.x = point.x;
+            transform.translation.y = point.y;
+            *visibility = Visibility::Visible;
+        } else {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
+
+/// Integrates bodies in a frozen gravity field, the same way the rollback schedule does
+struct Predictor {
+    field: GravityField,
+    /// Planets positions and radii
+    planets: Vec<(Vec2, f32)>,
+    integration: Integration,
+    delta: f32,
+    steps_per_dot: usize,
+}
+
+impl Predictor {
+    /// Returns one point per dot, stopping at the first planet hit
+    fn predict(
+        &self,
+        mut position: Vec2,
+        mut velocity: Vec2,
+        gravity_scale: f32,
+        radius: f32,
+        dots: usize,
+    ) -> Vec<Vec2> {
+        let substeps = self.integration.substeps.max(1);
+        let delta = self.delta / substeps as f32;
+        let acceleration = |position: Vec2| self.field.acceleration(position) * gravity_scale;
+
+        let mut points = Vec::with_capacity(dots);
+        'dots: for _ in 0..dots {
+            for _ in 0..self.steps_per_dot * substeps as usize {
+                self.integration
+                    .integrator
+                    .step(&mut position, &mut velocity, delta, acceleration);
+
+                let hits_planet = self.planets.iter().any(|(planet, planet_radius)| {
+                    position.distance_squared(*planet) < (radius + planet_radius).squared()
+                });
+                if hits_planet {
+                    break 'dots;
+                }
+            }
+            points.push(position);
+        }
+
+        points
+    }
+}
+
+impl Default for TrajectoryPreview {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            duration: 2.,
+            dots: 30,
+            show_projectile: true,
+        }
+    }
+}
@@ -26,6 +26,7 @@ pub enum WeaponType {
     Revolver,
     Pulse,
     BlackholeGun,
+    RocketLauncher,
 }
 
 #[derive(serde::Deserialize)]
@@ -1,18 +1,23 @@
 use crate::{
     GameState,
-    core::physics::{PhysicsSet, Position, Rotation, Velocity},
+    core::{
+        physics::{PhysicsSet, Position, Rotation, Velocity},
+        rng::{RngStream, RollbackRng},
+    },
     entities::{
+        player::status::Inflicts,
         projectile::{
-            Damage, DecayTimer, Projectile,
-            config::{BH_BULLET_DECAY_TIME, ProjectilesAssets, ProjectilesConfig},
+            Damage, DecayTimer, Knockback, ShockWave,
+            config::{ProjectilesAssets, ProjectilesConfig},
         },
         satellite::SatelliteSet,
     },
+    level::kills::Shooter,
+    network::events::RollbackEventWriter,
 };
 use bevy::{math::ops::cos, prelude::*};
 use bevy_ggrs::{AddRollbackCommandExtension, GgrsSchedule};
-use rand::{Rng as _, SeedableRng as _};
-use rand_xoshiro::Xoshiro256PlusPlus;
+use rand::Rng as _;
 
 pub mod assets;
 pub mod config;
@@ -40,7 +45,8 @@ pub struct WeaponState {
 #[relationship_target(relationship = super::Weapon)]
 pub struct Owner(Entity);
 
-#[derive(Event)]
+/// Written through `RollbackEventWriter` from the simulation, except for visibility changes
+#[derive(Event, Clone, Copy, Debug)]
 pub enum WeaponEvent {
     Fire(Entity),
     ReloadStart(Entity),
@@ -59,33 +65,44 @@ impl Plugin for WeaponPlugin {
             .register_type::<WeaponMode>()
             .register_required_components_with::<WeaponType, Name>(|| Name::new("Weapon"))
             .register_required_components::<WeaponType, WeaponMode>()
-            .add_event::<WeaponEvent>()
-            .add_plugins(sfx::WeaponSFXPlugin)
             .add_systems(
                 Update,
                 (
                     #[cfg(feature = "dev_tools")]
                     handle_config_reload,
-                    (
-                        add_stats,
-                        add_sprite,
-                        mode_change_detection,
-                        visibility_change_detection,
-                    )
+                    visibility_change_detection
                         .before(PhysicsSet::Player)
                         .run_if(in_state(GameState::InGame)),
                 ),
             )
             .add_systems(
                 GgrsSchedule,
-                (init_state, tick_weapon_timers, fire_weapon_system)
-                    .chain()
-                    .in_set(PhysicsSet::Interaction)
-                    .before(SatelliteSet::Slingshot),
+                (
+                    (add_stats, init_state)
+                        .chain()
+                        .after(super::inventory::spawn_arsenal)
+                        .before(PhysicsSet::Player),
+                    (tick_weapon_timers, fire_weapon_system)
+                        .chain()
+                        .in_set(PhysicsSet::Interaction)
+                        .before(SatelliteSet::Slingshot),
+                ),
             );
     }
 }
 
+pub struct WeaponVisualsPlugin;
+impl Plugin for WeaponVisualsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(sfx::WeaponSFXPlugin).add_systems(
+            Update,
+            add_sprite
+                .before(PhysicsSet::Player)
+                .run_if(in_state(GameState::InGame)),
+        );
+    }
+}
+
 fn add_stats(
     mut commands: Commands,
     query: Query<(Entity, &WeaponType), Without<WeaponStats>>,
@@ -107,14 +124,22 @@ fn add_stats(
     }
 }
 
+impl WeaponState {
+    /// Full magazine, ready to fire
+    pub fn new(stats: &WeaponStats) -> Self {
+        Self {
+            current_ammo: stats.magazine_size,
+            cooldown_timer: Timer::new(stats.cooldown, TimerMode::Once),
+            reload_timer: Timer::new(stats.reload_time, TimerMode::Once),
+        }
+    }
+}
+
 fn init_state(mut commands: Commands, query: Query<(Entity, &WeaponStats), Without<WeaponState>>) {
     for (weapon_entity, weapon_stats) in query.iter() {
-        commands.entity(weapon_entity).insert(WeaponState {
-            current_ammo: weapon_stats.magazine_size,
-            cooldown_timer: Timer::new(weapon_stats.cooldown, TimerMode::Once),
-            reload_timer: Timer::new(weapon_stats.reload_time, TimerMode::Once),
-        });
-        commands.entity(weapon_entity).insert(WeaponMode::Idle);
+        commands
+            .entity(weapon_entity)
+            .insert((WeaponState::new(weapon_stats), WeaponMode::Idle));
     }
 }
 
@@ -161,25 +186,10 @@ fn visibility_change_detection(
     }
 }
 
-fn mode_change_detection(
-    query: Query<(Entity, &WeaponMode), Changed<WeaponMode>>,
-    mut events: EventWriter<WeaponEvent>,
-) {
-    for (entity, mode) in query.iter() {
-        match mode {
-            WeaponMode::Idle => {}
-            WeaponMode::Triggered => {}
-            WeaponMode::Reloading => {
-                events.write(WeaponEvent::ReloadStart(entity));
-            }
-        };
-    }
-}
-
 /// Also handles ammo reloads for convenience
 fn tick_weapon_timers(
     mut query: Query<(Entity, &mut WeaponState, &mut WeaponMode, &WeaponStats), With<Position>>,
-    mut events: EventWriter<WeaponEvent>,
+    mut events: RollbackEventWriter<WeaponEvent>,
     time: Res<Time>,
 ) {
     for (entity, mut state, mut mode, stats) in query.iter_mut() {
@@ -210,71 +220,112 @@ fn fire_weapon_system(
         &WeaponStats,
         &Owner,
     )>,
-    mut owner_query: Query<&mut Velocity, Without<Owner>>,
-    mut events: EventWriter<WeaponEvent>,
+    mut owner_query: Query<(&mut Velocity, &super::PlayerStats, &super::Player), Without<Owner>>,
+    mut events: RollbackEventWriter<WeaponEvent>,
     projectiles_assets: Res<ProjectilesAssets>,
     projectiles_configs: Res<Assets<ProjectilesConfig>>,
-    time: Res<bevy_ggrs::RollbackFrameCount>,
+    mut rng: ResMut<RollbackRng>,
 ) {
     let Some(projectiles_config) = projectiles_configs.get(&projectiles_assets.config) else {
         warn!("Couldn't load ProjectileConfig");
         return;
     };
+    let rng = rng.stream(RngStream::Spread);
+
+    // Query iter order is non-deterministic, and weapons draw from the same stream
+    let mut firing = weapon_query
+        .iter()
+        .filter(|(_, state, mode, ..)| **mode == WeaponMode::Triggered && state.can_fire())
+        .map(|(entity, .., owner)| {
+            let handle = owner_query.get(owner.0).map(|(.., player)| player.handle);
+            (handle.ok(), entity)
+        })
+        .collect::<Vec<_>>();
+    firing.sort();
+
+    for (_, weapon_entity) in firing {
+        let Ok((entity, mut state, mut mode, position, velocity, rotation, stats, owner)) =
+            weapon_query.get_mut(weapon_entity)
+        else {
+            continue;
+        };
 
-    for (entity, mut state, mut mode, position, velocity, rotation, stats, owner) in
-        weapon_query.iter_mut()
-    {
-        if *mode == WeaponMode::Triggered && state.can_fire() {
-            // Putting it here is important as query iter order is non-deterministic
-            let mut rng = Xoshiro256PlusPlus::seed_from_u64(time.0 as u64);
-            for _ in 0..stats.shot_bullet_count {
-                if let Some(projectile_config) = projectiles_config.0.get(&stats.projectile) {
-                    let projectile_stats = &projectile_config.stats;
-                    let random_angle = rng.random_range(-stats.spread..stats.spread);
-
-                    let projectile_direction = Vec2::from_angle(rotation.0 + random_angle);
-                    // 1 if player and bullet directions points to the same direction, 0 if perpendicular, -1 if opposite
-                    let player_velocity_multiplier = cos(velocity.0.angle_to(projectile_direction));
-                    let added_velocity = velocity.0.length() * player_velocity_multiplier;
-
-                    let new_projectile = (
-                        stats.projectile,
-                        // Avoid bullet hitting player firing
-                        Position(position.0 + Vec2::from_angle(rotation.0) * super::PLAYER_RADIUS),
-                        Velocity(projectile_direction * (stats.projectile_speed + added_velocity)),
-                        Damage(stats.damage_multiplier * projectile_stats.damage),
-                    );
-                    let mut projectile_entity = commands.spawn(new_projectile);
-                    // Add decay for black hole bullets
-                    if stats.projectile == Projectile::Blackhole {
-                        projectile_entity.insert(DecayTimer(Timer::from_seconds(
-                            BH_BULLET_DECAY_TIME,
-                            TimerMode::Once,
-                        )));
+        let (owner_radius, shooter) = owner_query
+            .get(owner.0)
+            .map_or((0., None), |(_, owner_stats, player)| {
+                (owner_stats.radius, Some(Shooter(player.handle)))
+            });
+        for _ in 0..stats.shot_bullet_count {
+            if let Some(projectile_config) = projectiles_config.0.get(&stats.projectile) {
+                let projectile_stats = &projectile_config.stats;
+                let damage = Damage(stats.damage_multiplier * projectile_stats.damage);
+
+                if let Some(shockwave) = projectile_stats.shockwave {
+                    let mut shockwave_entity = commands.spawn((
+                        ShockWave::new(shockwave, owner.0),
+                        Position(position.0),
+                        damage,
+                        Knockback(projectile_stats.knockback),
+                    ));
+                    if let Some(shooter) = shooter {
+                        shockwave_entity.insert(shooter);
+                    }
+                    if !projectile_stats.effects.is_empty() {
+                        shockwave_entity.insert(Inflicts(projectile_stats.effects.clone()));
                     }
-                    projectile_entity.add_rollback();
-                } else {
-                    warn!("Empty projectile config!");
+                    shockwave_entity.add_rollback();
+                    continue;
                 }
-            }
 
-            events.write(WeaponEvent::Fire(entity));
+                let random_angle = rng.random_range(-stats.spread..stats.spread);
 
-            state.current_ammo -= 1;
-            // Reset timers if shooting
-            state.cooldown_timer.reset();
-            state.reload_timer.reset();
-            // Auto-reload if empty mag
-            if state.current_ammo == 0 {
-                *mode = WeaponMode::Reloading;
+                let projectile_direction = Vec2::from_angle(rotation.0 + random_angle);
+                // 1 if player and bullet directions points to the same direction, 0 if perpendicular, -1 if opposite
+                let player_velocity_multiplier = cos(velocity.0.angle_to(projectile_direction));
+                let added_velocity = velocity.0.length() * player_velocity_multiplier;
 
-                events.write(WeaponEvent::ReloadStart(entity));
-            }
-            // Recoil
-            if let Ok(mut owner_velocity) = owner_query.get_mut(owner.0) {
-                owner_velocity.0 -= Vec2::from_angle(rotation.0) * stats.recoil;
+                let new_projectile = (
+                    stats.projectile,
+                    // Avoid bullet hitting player firing
+                    Position(position.0 + Vec2::from_angle(rotation.0) * owner_radius),
+                    Velocity(projectile_direction * (stats.projectile_speed + added_velocity)),
+                    damage,
+                    Knockback(projectile_stats.knockback),
+                );
+                let mut projectile_entity = commands.spawn(new_projectile);
+                if let Some(shooter) = shooter {
+                    projectile_entity.insert(shooter);
+                }
+                if !projectile_stats.effects.is_empty() {
+                    projectile_entity.insert(Inflicts(projectile_stats.effects.clone()));
+                }
+                // E.g. black hole bullets collapsing into a black hole
+                if let Some(decay_time) = projectile_stats.decay_time {
+                    projectile_entity
+                        .insert(DecayTimer(Timer::from_seconds(decay_time, TimerMode::Once)));
+                }
+                projectile_entity.add_rollback();
+            } else {
+                warn!("Empty projectile config!");
             }
         }
+
+        events.write(WeaponEvent::Fire(entity));
+
+        state.current_ammo -= 1;
+        // Reset timers if shooting
+        state.cooldown_timer.reset();
+        state.reload_timer.reset();
+        // Auto-reload if empty mag
+        if state.current_ammo == 0 {
+            *mode = WeaponMode::Reloading;
+
+            events.write(WeaponEvent::ReloadStart(entity));
+        }
+        // Recoil
+        if let Ok((mut owner_velocity, ..)) = owner_query.get_mut(owner.0) {
+            owner_velocity.0 -= Vec2::from_angle(rotation.0) * stats.recoil;
+        }
     }
 }
 
@@ -300,7 +351,7 @@ impl WeaponEvent {
 fn handle_config_reload(
     mut commands: Commands,
     mut events: EventReader<AssetEvent<config::WeaponsConfig>>,
-    weapons: Query<Entity, (With<WeaponStats>, With<Sprite>)>,
+    weapons: Query<Entity, With<WeaponStats>>,
 ) {
     for event in events.read() {
         if let AssetEvent::Modified { id: _ } = event {
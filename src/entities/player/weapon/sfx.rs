@@ -28,7 +28,10 @@ fn handle_weapon_events(
 ) -> Result {
     for event in events.read() {
         let entity = event.get_entity();
-        let (weapon_type, audio_reload) = weapon_query.get(entity)?;
+        // Simulation events are only received once confirmed, the weapon may be gone by then
+        let Ok((weapon_type, audio_reload)) = weapon_query.get(entity) else {
+            continue;
+        };
         let weapon_assets = assets.get(weapon_type).ok_or(BevyError::from(format!(
             "Failed to retreive weapon assets for {weapon_type:?}",
         )))?;
@@ -1,9 +1,8 @@
 use bevy::{platform::collections::HashMap, prelude::*};
 use bevy_asset_loader::asset_collection::AssetCollection;
 
-// TODO: add a config file
-pub static BLACKHOLE_RADIUS: u32 = 60;
-pub static BH_BULLET_DECAY_TIME: f32 = 0.5;
+use super::{ExplosionStats, HitscanStats, KnockbackStats, ProjectileInteraction, ShockWaveStats};
+use crate::entities::player::status::StatusEffectConfig;
 
 #[derive(AssetCollection, Resource)]
 pub struct ProjectilesAssets {
@@ -36,9 +35,37 @@ pub struct ProjectileConfig {
 #[derive(serde::Deserialize)]
 pub struct ProjectileStats {
     pub mass: u32,
-    #[allow(dead_code)] // Will be used later when we have different projectiles
+    /// Also used as the half width of beams
     pub radius: f32,
     pub damage: f32,
+    /// How hit players get launched, defaults to the percentage scaled impulse
+    #[serde(default)]
+    pub knockback: KnockbackStats,
+    /// Seconds before the projectile decays, yielding a `ProjectileDecayedEvent`
+    #[serde(default)]
+    pub decay_time: Option<f32>,
+    /// How much the projectile is subject to gravity
+    #[serde(default = "default_gravity_scale")]
+    pub gravity_scale: f32,
+    /// Makes the projectile explode instead of hitting
+    #[serde(default)]
+    pub explosion: Option<ExplosionStats>,
+    /// Resolves the projectile as an instant ray instead of a moving body
+    #[serde(default)]
+    pub hitscan: Option<HitscanStats>,
+    /// Fires an expanding ring around the shooter instead of a moving body
+    #[serde(default)]
+    pub shockwave: Option<ShockWaveStats>,
+    /// Status effects inflicted to the players it hits
+    #[serde(default)]
+    pub effects: Vec<StatusEffectConfig>,
+    /// What happens when hitting other projectiles, by their type
+    #[serde(default)]
+    pub interactions: HashMap<Projectile, ProjectileInteraction>,
+}
+
+fn default_gravity_scale() -> f32 {
+    1.
 }
 
 #[derive(Clone, Debug, serde::Deserialize)]
@@ -0,0 +1,170 @@
+use bevy::prelude::*;
+
+use super::{
+    Damage, Knockback, KnockbackHit, PlayerCollision, Projectile, ProjectileDecayedEvent,
+    ProjectilesAssets, ProjectilesConfig,
+};
+use crate::{
+    core::{
+        collision::CollisionState,
+        gravity::Mass,
+        physics::{Position, Rotation, Velocity},
+    },
+    entities::{
+        planet::Planet,
+        player::{
+            Percentage, Player,
+            status::{Inflicts, StatusEffects},
+        },
+    },
+    level::{
+        kills::{LastHitBy, Shooter},
+        stocks::Invulnerable,
+    },
+    network::events::RollbackEventReader,
+};
+
+/// Area of effect of an exploding projectile
+#[derive(serde::Deserialize, Clone, Copy, Debug, Reflect)]
+pub struct ExplosionStats {
+    /// Blast radius, players further away are left untouched
+    pub radius: f32,
+    /// Knockback dealt at the center of the blast, akin to `mass * velocity` of a bullet
+    pub impulse: f32,
+    /// Fraction of the impulse and damage lost at the edge of the blast.
+    /// `0.` is uniform, `1.` linearly fades out to nothing.
+    pub falloff: f32,
+}
+
+impl ExplosionStats {
+    /// Strength multiplier at `distance` from the center, `0.` outside of the blast
+    pub fn strength(&self, distance: f32) -> f32 {
+        if distance > self.radius || self.radius <= 0. {
+            return 0.;
+        }
+
+        1. - self.falloff.clamp(0., 1.) * distance / self.radius
+    }
+}
+
+/// Makes a projectile explode instead of hitting, when touching a planet, a player or decaying
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+pub struct Explosive(pub ExplosionStats);
+
+/// Explodes projectiles based on last frame collisions, before players get moved so that
+/// grounded players can get blown off (e.g. rocket jumping).
+pub(super) fn explode_projectiles(
+    mut commands: Commands,
+    decay_events: RollbackEventReader<ProjectileDecayedEvent>,
+    projectile_query: Query<
+        (
+            Entity,
+            &Position,
+            &Explosive,
+            &Damage,
+            &CollisionState<Projectile, Planet>,
+            &PlayerCollision,
+            Option<&Shooter>,
+            Option<&Inflicts>,
+            Option<&Knockback>,
+        ),
+        Without<Player>,
+    >,
+    mut player_query: Query<
+        (
+            Entity,
+            &Player,
+            &Position,
+            &Rotation,
+            &mut Velocity,
+            &Mass,
+            &mut Percentage,
+            &mut StatusEffects,
+        ),
+        (With<Player>, Without<Projectile>, Without<Invulnerable>),
+    >,
+    assets: Res<ProjectilesAssets>,
+    configs: Res<Assets<ProjectilesConfig>>,
+    time: Res<Time>,
+) {
+    let Some(config) = configs.get(&assets.config) else {
+        warn!("Couldn't load ProjectileConfig");
+        return;
+    };
+
+    // Decayed projectiles are already despawned, fall back on their base stats
+    let decayed = decay_events.read().filter_map(|event| {
+        let stats = &config.0.get(&event.r#type?)?.stats;
+        Some((
+            event.position.clone(),
+            stats.explosion?,
+            stats.damage,
+            event.shooter,
+            stats.effects.as_slice(),
+            stats.knockback,
+        ))
+    });
+
+    // Need to sort for determinism, as percentages add up
+    let mut collided = projectile_query
+        .iter()
+        .filter(|(.., planet_collision, player_collision, _, _, _)| {
+            planet_collision.collides || player_collision.collides
+        })
+        .collect::<Vec<_>>();
+    collided.sort_by_key(|(_, position, ..)| *position);
+
+    let mut explosions = decayed.collect::<Vec<_>>();
+    for (projectile, position, explosive, damage, .., shooter, inflicts, knockback) in collided {
+        explosions.push((
+            position.clone(),
+            explosive.0,
+            damage.0,
+            shooter.copied(),
+            inflicts.map_or(&[][..], |inflicts| inflicts.0.as_slice()),
+            knockback.map_or_else(default, |knockback| knockback.0),
+        ));
+        commands.entity(projectile).despawn();
+    }
+
+    for (center, explosion, damage, shooter, effects, knockback) in explosions.iter() {
+        for (
+            player_entity,
+            player,
+            player_position,
+            player_rotation,
+            mut player_velocity,
+            player_mass,
+            mut player_percentage,
+            mut player_effects,
+        ) in player_query.iter_mut()
+        {
+            let offset = player_position.0 - center.0;
+            let strength = explosion.strength(offset.length());
+            if strength <= 0. {
+                continue;
+            }
+
+            if let Some(last_hit) = LastHitBy::new(shooter.as_ref(), player) {
+                commands.entity(player_entity).insert(last_hit);
+            }
+
+            player_percentage.0 += damage * strength;
+            player_velocity.0 += knockback.velocity(&KnockbackHit {
+                impulse: offset.normalize_or_zero()
+                    * explosion.impulse
+                    * strength
+                    * player_effects.knockback_multiplier(),
+                up: Vec2::from_angle(player_rotation.0).rotate(Vec2::Y),
+                percentage: player_percentage.0,
+                damage: damage * strength,
+                mass: player_mass.0 as f32,
+            });
+
+            if let Some(hitstun) = knockback.hitstun(time.delta_secs()) {
+                player_effects.apply(&hitstun);
+            }
+            player_effects.apply_all(effects.iter());
+        }
+    }
+}
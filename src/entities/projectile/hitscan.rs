@@ -0,0 +1,197 @@
+use bevy::{math::ops, prelude::*};
+use bevy_ggrs::AddRollbackCommandExtension;
+
+use super::{
+    Damage, DecayTimer, Knockback, KnockbackHit, Projectile, ProjectilesAssets, ProjectilesConfig,
+};
+use crate::{
+    core::{
+        collision::{CollisionShape, CollisionState, ray_cast},
+        gravity::Mass,
+        physics::{Position, Rotation, Velocity},
+    },
+    entities::{
+        planet::Planet,
+        player::{
+            Percentage, Player,
+            status::{Inflicts, StatusEffectConfig, StatusEffects},
+        },
+    },
+    level::{
+        kills::{LastHitBy, Shooter},
+        stocks::Invulnerable,
+    },
+};
+
+/// Instant ray cast replacing the projectile flight
+#[derive(serde::Deserialize, Clone, Copy, Debug, Reflect)]
+pub struct HitscanStats {
+    /// Maximum distance travelled by the ray
+    pub range: f32,
+    /// Seconds the beam stays visible
+    pub beam_duration: f32,
+}
+
+/// Makes a projectile resolve as a ray cast on the frame it gets fired
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+pub struct Hitscan(pub HitscanStats);
+
+/// Trace left by a hitscan projectile, centered on the middle of the ray
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[require(Name::new("Beam"))]
+pub struct Beam {
+    pub projectile: Projectile,
+    pub length: f32,
+}
+
+type ShapeQuery<'w, 's, T> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Position,
+        Option<&'static Rotation>,
+        &'static CollisionShape,
+    ),
+    With<T>,
+>;
+
+/// Rays stop at the first planet, and hit the first player in front of it
+pub(super) fn fire_hitscans(
+    mut commands: Commands,
+    query: Query<(
+        Entity,
+        &Projectile,
+        &Position,
+        &Velocity,
+        &Mass,
+        &Damage,
+        &Hitscan,
+        Option<&Shooter>,
+        Option<&Inflicts>,
+        Option<&Knockback>,
+    )>,
+    planet_query: ShapeQuery<Planet>,
+    player_shape_query: ShapeQuery<Player>,
+    mut player_query: Query<
+        (
+            &Player,
+            &Rotation,
+            &mut Velocity,
+            &Mass,
+            &mut Percentage,
+            &mut StatusEffects,
+            Option<&CollisionState<Player, Planet>>,
+        ),
+        (With<Player>, Without<Projectile>, Without<Invulnerable>),
+    >,
+    time: Res<Time>,
+) {
+    // Need to sort rays for determinism in case multiple rays hits a player at once
+    let mut hitscans = query.iter().collect::<Vec<_>>();
+    hitscans.sort_by_key(|(_, _, position, ..)| *position);
+
+    for (
+        projectile,
+        projectile_type,
+        position,
+        velocity,
+        mass,
+        damage,
+        hitscan,
+        shooter,
+        inflicts,
+        knockback,
+    ) in hitscans
+    {
+        commands.entity(projectile).despawn();
+
+        let Ok(direction) = Dir2::new(velocity.0) else {
+            continue;
+        };
+        let from = position.0;
+        let to = from + direction * hitscan.0.range;
+
+        let planet_hit = ray_cast(from, to, planet_query.iter());
+        let player_hit = ray_cast(from, to, player_shape_query.iter())
+            .filter(|hit| planet_hit.is_none_or(|planet_hit| hit.time < planet_hit.time));
+
+        if let Some(hit) = player_hit
+            && let Ok((
+                player,
+                player_rotation,
+                mut player_velocity,
+                player_mass,
+                mut player_percentage,
+                mut player_effects,
+                player_collision,
+            )) = player_query.get_mut(hit.entity)
+        {
+            if let Some(last_hit) = LastHitBy::new(shooter, player) {
+                commands.entity(hit.entity).insert(last_hit);
+            }
+
+            player_percentage.0 += damage.0;
+            let knockback = knockback.map_or_else(default, |knockback| knockback.0);
+            player_velocity.0 += knockback.velocity(&KnockbackHit {
+                impulse: velocity.0 * mass.0 as f32 * player_effects.knockback_multiplier(),
+                up: Vec2::from_angle(player_rotation.0).rotate(Vec2::Y),
+                percentage: player_percentage.0,
+                damage: damage.0,
+                mass: player_mass.0 as f32,
+            });
+
+            if player_collision.is_some_and(|collision| collision.collides) {
+                player_effects.apply(&StatusEffectConfig::HIT_STUN);
+            }
+            if let Some(hitstun) = knockback.hitstun(time.delta_secs()) {
+                player_effects.apply(&hitstun);
+            }
+            if let Some(inflicts) = inflicts {
+                player_effects.apply_all(&inflicts.0);
+            }
+        }
+
+        let end = player_hit.or(planet_hit).map_or(to, |hit| hit.point);
+        commands
+            .spawn((
+                Beam {
+                    projectile: *projectile_type,
+                    length: from.distance(end),
+                },
+                Position(from.midpoint(end)),
+                Rotation(ops::atan2(direction.y, direction.x)),
+                DecayTimer(Timer::from_seconds(
+                    hitscan.0.beam_duration,
+                    TimerMode::Once,
+                )),
+            ))
+            .add_rollback();
+    }
+}
+
+pub(super) fn add_beam_sprite(
+    mut commands: Commands,
+    query: Query<(Entity, &Beam), Without<Sprite>>,
+    assets: Res<ProjectilesAssets>,
+    configs: Res<Assets<ProjectilesConfig>>,
+    asset_server: Res<AssetServer>,
+) {
+    let Some(config) = configs.get(&assets.config) else {
+        warn!("Couldn't load ProjectileConfig");
+        return;
+    };
+
+    for (beam_entity, beam) in query.iter() {
+        if let Some(projectile_config) = config.0.get(&beam.projectile) {
+            commands.entity(beam_entity).insert((
+                Sprite {
+                    image: asset_server.load(&projectile_config.skin.sprite),
+                    custom_size: Some(Vec2::new(beam.length, projectile_config.stats.radius * 2.)),
+                    ..default()
+                },
+                Transform::from_xyz(0.0, 0.0, 10.0 + 1.0),
+            ));
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use bevy::prelude::*;
+
+use super::{Projectile, ProjectileDecayedEvent, ProjectilesAssets, ProjectilesConfig};
+use crate::{
+    core::{
+        collision::CollisionState,
+        gravity::Mass,
+        physics::{Position, Velocity},
+    },
+    level::kills::Shooter,
+    network::events::RollbackEventWriter,
+};
+
+type ProjectileCollision = CollisionState<Projectile, Projectile>;
+
+/// Outcome of a projectile hitting another one
+#[derive(serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum ProjectileInteraction {
+    /// Both projectiles get destroyed
+    Cancel,
+    /// Both projectiles bounce off each other, exchanging momentum
+    Deflect,
+    /// Both projectiles decay on the spot, e.g. a bullet setting a rocket off
+    Detonate,
+}
+
+impl ProjectilesConfig {
+    /// Looks up the outcome of `a` hitting `b`, falling back on the one of `b` hitting `a`.
+    /// Projectiles without matching rules pass through each other.
+    pub fn interaction(&self, a: &Projectile, b: &Projectile) -> Option<ProjectileInteraction> {
+        let rule = |from: &Projectile, to: &Projectile| {
+            self.0.get(from)?.stats.interactions.get(to).copied()
+        };
+        rule(a, b).or_else(|| rule(b, a))
+    }
+}
+
+/// Resolves last frame collisions between projectiles, before explosions so that detonated
+/// projectiles blow up on the same frame.
+pub(super) fn check_projectile_collisions(
+    mut commands: Commands,
+    mut decay_events: RollbackEventWriter<ProjectileDecayedEvent>,
+    mut query: Query<(
+        Entity,
+        &Projectile,
+        &Position,
+        &mut Velocity,
+        &Mass,
+        &ProjectileCollision,
+        Option<&Shooter>,
+    )>,
+    assets: Res<ProjectilesAssets>,
+    configs: Res<Assets<ProjectilesConfig>>,
+) {
+    let Some(config) = configs.get(&assets.config) else {
+        warn!("Couldn't load ProjectileConfig");
+        return;
+    };
+
+    // Need to sort projectiles for determinism, as each one gets resolved only once
+    let mut colliding = query
+        .iter()
+        .filter_map(|(entity, _, position, .., collision, _)| {
+            let other = collision.closest.filter(|_| collision.collides)?;
+            Some((entity, other, position.clone()))
+        })
+        .collect::<Vec<_>>();
+    colliding.sort_by_key(|(.., position)| position.clone());
+
+    let mut resolved = Vec::<Entity>::new();
+    for (entity, other, _) in colliding {
+        if resolved.contains(&entity) || resolved.contains(&other) {
+            continue;
+        }
+        let Ok([a, b]) = query.get_many_mut([entity, other]) else {
+            continue;
+        };
+        let Some(interaction) = config.interaction(a.1, b.1) else {
+            continue;
+        };
+        resolved.extend([entity, other]);
+
+        match interaction {
+            ProjectileInteraction::Cancel => {
+                commands.entity(entity).despawn();
+                commands.entity(other).despawn();
+            }
+            ProjectileInteraction::Deflect => {
+                let (_, _, a_position, mut a_velocity, a_mass, ..) = a;
+                let (_, _, b_position, mut b_velocity, b_mass, ..) = b;
+
+                let normal = (b_position.0 - a_position.0).normalize_or_zero();
+                let approach_speed = (a_velocity.0 - b_velocity.0).dot(normal);
+                let total_mass = (a_mass.0 + b_mass.0) as f32;
+                // Already moving apart, or nothing to bounce off
+                if approach_speed <= 0. || total_mass <= 0. {
+                    continue;
+                }
+
+                // Elastic collision along the contact normal
+                let impulse = 2. * approach_speed / total_mass * normal;
+                a_velocity.0 -= impulse * b_mass.0 as f32;
+                b_velocity.0 += impulse * a_mass.0 as f32;
+            }
+            ProjectileInteraction::Detonate => {
+                for (entity, projectile, position, .., shooter) in [a, b] {
+                    decay_events.write(ProjectileDecayedEvent {
+                        position: position.clone(),
+                        r#type: Some(*projectile),
+                        shooter: shooter.copied(),
+                    });
+                    commands.entity(entity).despawn();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use bevy::prelude::*;
+
+use crate::entities::player::status::{Stacking, StatusEffect, StatusEffectConfig};
+
+/// How a projectile launches the players it hits
+#[derive(serde::Deserialize, Clone, Copy, Debug, PartialEq, Reflect)]
+#[serde(default)]
+pub struct KnockbackStats {
+    /// Impulse multiplier dealt regardless of the target percentage
+    pub base: f32,
+    /// Impulse multiplier added per point of target percentage, after damage got dealt
+    pub growth: f32,
+    /// Launch angle in degrees from the ground, away from the shooter. Follows the hit direction if `None`
+    pub angle: Option<f32>,
+    /// Frames during which the target is stunned after getting hit
+    pub hitstun_frames: u32,
+    /// Impulse multiplier added per point of damage of the hit
+    pub damage_weight: f32,
+}
+
+impl KnockbackStats {
+    /// `(1 + percentage) * impulse / mass`, which every weapon used before knockback got configurable
+    pub const LEGACY: Self = Self {
+        base: 1.,
+        growth: 1.,
+        angle: None,
+        hitstun_frames: 0,
+        damage_weight: 0.,
+    };
+
+    /// Velocity added to the target of `hit`
+    pub fn velocity(&self, hit: &KnockbackHit) -> Vec2 {
+        if hit.mass <= 0. {
+            return Vec2::ZERO;
+        }
+
+        let scale = self.base + self.growth * hit.percentage + self.damage_weight * hit.damage;
+        let direction = match self.angle {
+            Some(angle) => {
+                let up = hit.up.normalize_or_zero();
+                // Keeps launching away from the shooter, along the ground
+                let along = hit
+                    .impulse
+                    .reject_from_normalized(up)
+                    .normalize_or(up.perp());
+                let launch = Vec2::from_angle(angle.to_radians());
+                along * launch.x + up * launch.y
+            }
+            None => hit.impulse.normalize_or_zero(),
+        };
+
+        direction * hit.impulse.length() * scale / hit.mass
+    }
+
+    /// Stun inflicted on hit, lasting `hitstun_frames` frames of `frame_duration` seconds
+    pub fn hitstun(&self, frame_duration: f32) -> Option<StatusEffectConfig> {
+        (self.hitstun_frames > 0).then(|| StatusEffectConfig {
+            effect: StatusEffect::Stun,
+            duration: self.hitstun_frames as f32 * frame_duration,
+            stacking: Stacking::Refresh,
+        })
+    }
+}
+
+impl Default for KnockbackStats {
+    fn default() -> Self {
+        Self::LEGACY
+    }
+}
+
+/// Knockback stats of a projectile, carried over to whatever it spawns
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub struct Knockback(pub KnockbackStats);
+
+/// Inputs of a knockback computation
+#[derive(Clone, Copy, Debug, Default)]
+pub struct KnockbackHit {
+    /// Raw impulse of the hit, e.g. `mass * velocity` of a bullet
+    pub impulse: Vec2,
+    /// Up direction of the target, used by angle overrides
+    pub up: Vec2,
+    /// Target percentage, damage of the hit included
+    pub percentage: f32,
+    pub damage: f32,
+    /// Target mass
+    pub mass: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::projectile::ProjectilesConfig;
+
+    const EPSILON: f32 = 1e-4;
+
+    fn legacy_formula(hit: &KnockbackHit) -> Vec2 {
+        (1.0 + hit.percentage) * hit.impulse / hit.mass
+    }
+
+    #[test]
+    fn test_legacy_matches_fixed_formula() {
+        let hits = [
+            KnockbackHit {
+                impulse: Vec2::new(100. * 800., 0.),
+                up: Vec2::Y,
+                percentage: 0.01,
+                damage: 0.01,
+                mass: 1000.,
+            },
+            KnockbackHit {
+                impulse: Vec2::new(-3000., 4500.),
+                up: Vec2::X,
+                percentage: 1.5,
+                damage: 0.2,
+                mass: 500.,
+            },
+            KnockbackHit {
+                impulse: Vec2::new(0., -120000.),
+                up: Vec2::NEG_Y,
+                percentage: 0.,
+                damage: 0.,
+                mass: 2000.,
+            },
+        ];
+
+        for hit in hits.iter() {
+            let velocity = KnockbackStats::LEGACY.velocity(hit);
+            let expected = legacy_formula(hit);
+            assert!(
+                velocity.abs_diff_eq(expected, EPSILON * expected.length()),
+                "{velocity} != {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_default_is_legacy() {
+        assert_eq!(KnockbackStats::default(), KnockbackStats::LEGACY);
+    }
+
+    #[test]
+    fn test_zero_growth_ignores_percentage() {
+        let stats = KnockbackStats {
+            growth: 0.,
+            ..KnockbackStats::LEGACY
+        };
+        let hit = KnockbackHit {
+            impulse: Vec2::new(1000., 0.),
+            up: Vec2::Y,
+            percentage: 0.,
+            damage: 0.,
+            mass: 10.,
+        };
+        let damaged = KnockbackHit {
+            percentage: 2.,
+            ..hit
+        };
+
+        assert_eq!(stats.velocity(&hit), stats.velocity(&damaged));
+        assert!(
+            stats
+                .velocity(&hit)
+                .abs_diff_eq(Vec2::new(100., 0.), EPSILON)
+        );
+    }
+
+    #[test]
+    fn test_damage_weight() {
+        let stats = KnockbackStats {
+            base: 0.,
+            growth: 0.,
+            damage_weight: 2.,
+            ..KnockbackStats::LEGACY
+        };
+        let hit = KnockbackHit {
+            impulse: Vec2::new(0., 100.),
+            up: Vec2::Y,
+            percentage: 5.,
+            damage: 0.5,
+            mass: 1.,
+        };
+
+        assert!(
+            stats
+                .velocity(&hit)
+                .abs_diff_eq(Vec2::new(0., 100.), EPSILON)
+        );
+    }
+
+    #[test]
+    fn test_angle_override() {
+        let stats = KnockbackStats {
+            angle: Some(45.),
+            ..KnockbackStats::LEGACY
+        };
+        let hit = KnockbackHit {
+            impulse: Vec2::new(-10., -10.),
+            up: Vec2::Y,
+            percentage: 0.,
+            damage: 0.,
+            mass: 1.,
+        };
+        let velocity = stats.velocity(&hit);
+        let expected = Vec2::new(-1., 1.).normalize() * hit.impulse.length();
+        assert!(
+            velocity.abs_diff_eq(expected, EPSILON),
+            "{velocity} != {expected}"
+        );
+
+        // Straight up, regardless of the hit direction
+        let stats = KnockbackStats {
+            angle: Some(90.),
+            ..stats
+        };
+        let hit = KnockbackHit { up: Vec2::X, ..hit };
+        let velocity = stats.velocity(&hit);
+        assert!(velocity.normalize().abs_diff_eq(Vec2::X, EPSILON));
+    }
+
+    #[test]
+    fn test_massless_target() {
+        let hit = KnockbackHit {
+            impulse: Vec2::ONE,
+            mass: 0.,
+            ..default()
+        };
+        assert_eq!(KnockbackStats::LEGACY.velocity(&hit), Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_hitstun() {
+        assert_eq!(KnockbackStats::LEGACY.hitstun(1. / 60.), None);
+
+        let stats = KnockbackStats {
+            hitstun_frames: 30,
+            ..KnockbackStats::LEGACY
+        };
+        let hitstun = stats.hitstun(1. / 60.).expect("Should stun");
+        assert_eq!(hitstun.effect, StatusEffect::Stun);
+        assert!((hitstun.duration - 0.5).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_shipped_config_uses_legacy() {
+        let config: ProjectilesConfig = ron::de::from_str(include_str!(
+            "../../../assets/config/config.projectiles.ron"
+        ))
+        .expect("Shipped projectiles config should parse");
+
+        // Rebalancing the knockback of a projectile should be a deliberate change to this test
+        for (projectile, config) in config.0.iter() {
+            assert_eq!(
+                config.stats.knockback,
+                KnockbackStats::LEGACY,
+                "{projectile:?} knockback"
+            );
+        }
+        assert_eq!(config.0.len(), 5);
+    }
+}
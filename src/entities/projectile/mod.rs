@@ -1,27 +1,46 @@
 use super::{planet::Planet, player::Player};
 use crate::{
     core::{
-        collision::{CollisionPlugin, CollisionShape, CollisionState},
-        gravity::{Mass, Passive},
+        collision::{
+            CollisionPlugin, CollisionShape, CollisionStarted, CollisionState, SweptCollision,
+        },
+        gravity::{GravityReceiverScale, Mass, Passive},
         physics::{PhysicsSet, Position, Rotation, Velocity},
     },
-    entities::player::{Percentage, Stunned},
+    entities::player::{
+        Percentage,
+        status::{Inflicts, StatusEffectConfig, StatusEffects},
+    },
+    level::{
+        kills::{LastHitBy, Shooter},
+        stocks::Invulnerable,
+    },
+    network::events::RollbackEventWriter,
 };
 use bevy::prelude::*;
 use bevy_ggrs::GgrsSchedule;
 
 pub mod config;
+mod explosion;
+mod hitscan;
+mod interaction;
+mod knockback;
+mod shockwave;
 use config::ProjectilesAssets;
 pub use config::{Projectile, ProjectilesConfig};
+pub use explosion::{ExplosionStats, Explosive};
+pub use hitscan::{Beam, Hitscan, HitscanStats};
+pub use interaction::ProjectileInteraction;
+pub use knockback::{Knockback, KnockbackHit, KnockbackStats};
+pub use shockwave::{ShockWave, ShockWaveStats};
 
-type PlanetCollision = CollisionState<Projectile, Planet>;
 type PlayerCollision = CollisionState<Projectile, Player>;
 
 /// Autodespawn timer. Yields `ProjectileDecayedEvent`.
 #[derive(Component, Debug, Clone, Reflect)]
 pub struct DecayTimer(pub Timer);
 
-// Possible fix for events
+/// Written through `RollbackEventWriter`, so that rollbacks don't duplicate nor drop decays
 #[derive(Event, Debug, Reflect, Clone)]
 pub struct ProjectileDecayedEvent {
     /// Where the projectile decayed
@@ -30,6 +49,8 @@ pub struct ProjectileDecayedEvent {
     /// Can be None if the entity isn't a projectile, so that decay functionality can be used in other modules.
     /// Might get moved somewhere else in the future
     pub r#type: Option<Projectile>,
+    /// Who fired the projectile, passed on to whatever it decays into
+    pub shooter: Option<Shooter>,
 }
 
 #[derive(Component, Clone, Copy, Debug, Reflect)]
@@ -40,30 +61,38 @@ impl Plugin for ProjectilePlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<Damage>()
             .register_type::<DecayTimer>()
+            .register_type::<Explosive>()
+            .register_type::<Hitscan>()
+            .register_type::<Beam>()
+            .register_type::<ShockWave>()
+            .register_type::<Knockback>()
             .register_required_components::<Projectile, CollisionShape>()
+            // Projectiles are fast enough to tunnel through players in a single frame
+            .register_required_components::<Projectile, SweptCollision>()
             .register_required_components_with::<Projectile, Transform>(|| {
                 Transform::from_scale(Vec3::splat(1.5))
             })
             .register_required_components_with::<Projectile, Rotation>(|| Rotation(0.))
             .register_required_components_with::<Projectile, Passive>(|| Passive)
             .register_required_components_with::<Projectile, Name>(|| Name::new("Projectile"))
-            .add_event::<ProjectileDecayedEvent>()
             .add_plugins(CollisionPlugin::<Projectile, Planet>::new())
             .add_plugins(CollisionPlugin::<Projectile, Player>::new())
-            .add_systems(
-                Update,
-                (add_sprite, rotate_sprite)
-                    .chain()
-                    .run_if(resource_exists::<ProjectilesAssets>),
-            )
+            .add_plugins(CollisionPlugin::<Projectile, Projectile>::new())
             .add_systems(
                 GgrsSchedule,
                 (
                     tick_decay_timers.before(PhysicsSet::Player),
-                    add_physical_properties
+                    (add_physical_properties, hitscan::fire_hitscans)
+                        .chain()
                         .before(PhysicsSet::Gravity)
                         .after(PhysicsSet::Player),
-                    check_player_collisions
+                    (
+                        interaction::check_projectile_collisions,
+                        explosion::explode_projectiles,
+                        check_player_collisions,
+                        shockwave::expand_shockwaves,
+                    )
+                        .chain()
                         .before(PhysicsSet::Movement)
                         .after(PhysicsSet::Interaction),
                     check_planet_collisions.after(PhysicsSet::Collision),
@@ -72,6 +101,21 @@ impl Plugin for ProjectilePlugin {
     }
 }
 
+pub struct ProjectileVisualsPlugin;
+impl Plugin for ProjectileVisualsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                (add_sprite, rotate_sprite).chain(),
+                hitscan::add_beam_sprite,
+                shockwave::draw_shockwaves,
+            )
+                .run_if(resource_exists::<ProjectilesAssets>),
+        );
+    }
+}
+
 fn add_physical_properties(
     mut commands: Commands,
     query: Query<(Entity, &Projectile), Without<Mass>>,
@@ -87,9 +131,18 @@ fn add_physical_properties(
         if let Some(projectile_config) = config.0.get(projectile_type) {
             let projectile_stats = &projectile_config.stats;
 
-            commands
-                .entity(projectile_entity)
-                .insert(Mass(projectile_stats.mass));
+            commands.entity(projectile_entity).insert((
+                Mass(projectile_stats.mass),
+                GravityReceiverScale(projectile_stats.gravity_scale),
+            ));
+            if let Some(explosion) = projectile_stats.explosion {
+                commands
+                    .entity(projectile_entity)
+                    .insert(Explosive(explosion));
+            }
+            if let Some(hitscan) = projectile_stats.hitscan {
+                commands.entity(projectile_entity).insert(Hitscan(hitscan));
+            }
         }
     }
 }
@@ -126,17 +179,23 @@ fn rotate_sprite(mut query: Query<(&mut Rotation, &Velocity), (With<Projectile>,
 
 fn tick_decay_timers(
     mut commands: Commands,
-    mut projectile_decay_events: EventWriter<ProjectileDecayedEvent>,
-    mut projectiles_query: Query<(Entity, Option<&Projectile>, &Position, &mut DecayTimer)>,
+    mut projectile_decay_events: RollbackEventWriter<ProjectileDecayedEvent>,
+    mut projectiles_query: Query<(
+        Entity,
+        Option<&Projectile>,
+        Option<&Shooter>,
+        &Position,
+        &mut DecayTimer,
+    )>,
     time: Res<Time>,
 ) {
-    for (entity, projectile, position, mut despawn_timer) in projectiles_query.iter_mut() {
+    for (entity, projectile, shooter, position, mut despawn_timer) in projectiles_query.iter_mut() {
         despawn_timer.0.tick(time.delta());
         if despawn_timer.0.just_finished() {
-            // TODO: Control on events rollback
             projectile_decay_events.write(ProjectileDecayedEvent {
                 position: position.clone(),
                 r#type: projectile.cloned(),
+                shooter: shooter.copied(),
             });
             commands.entity(entity).despawn();
         }
@@ -145,12 +204,15 @@ fn tick_decay_timers(
 
 fn check_planet_collisions(
     mut commands: Commands,
-    query: Query<(Entity, &PlanetCollision), With<Projectile>>,
+    mut collisions: EventReader<CollisionStarted<Projectile, Planet>>,
+    explosive_query: Query<(), With<Explosive>>,
 ) {
-    for (projectile, planet_collision) in query.iter() {
-        if planet_collision.collides {
-            commands.entity(projectile).despawn();
+    for collision in collisions.read() {
+        // Explode on next frame instead
+        if explosive_query.contains(collision.entity) {
+            continue;
         }
+        commands.entity(collision.entity).despawn();
     }
 }
 
@@ -164,25 +226,32 @@ fn check_player_collisions(
             &Mass,
             &PlayerCollision,
             &Damage,
+            Option<&Shooter>,
+            Option<&Inflicts>,
+            Option<&Knockback>,
         ),
-        With<Projectile>,
+        (With<Projectile>, Without<Explosive>),
     >,
     mut player_query: Query<
         (
+            &Player,
+            &Rotation,
             &mut Velocity,
             &Mass,
             &mut Percentage,
+            &mut StatusEffects,
             Option<&CollisionState<Player, Planet>>,
         ),
-        Without<Projectile>,
+        (Without<Projectile>, Without<Invulnerable>),
     >,
+    time: Res<Time>,
 ) {
     // Need to sort bullets for determinism in case multiple bullets hits a player at once
     let mut colliding_bullets = query
         .iter()
-        .filter(|(_, _, _, _, collision, _)| collision.collides)
+        .filter(|(_, _, _, _, collision, ..)| collision.collides)
         .collect::<Vec<_>>();
-    colliding_bullets.sort_by_key(|(_, pos, _, _, _, _)| *pos);
+    colliding_bullets.sort_by_key(|(_, pos, ..)| *pos);
 
     for (
         projectile,
@@ -191,19 +260,45 @@ fn check_player_collisions(
         projectile_mass,
         projectile_collision,
         projectile_damage,
+        shooter,
+        inflicts,
+        knockback,
     ) in colliding_bullets.into_iter()
     {
         if let Some(closest_player) = projectile_collision.closest
-            && let Ok((mut player_velocity, player_mass, mut player_percentage, player_collision)) =
-                player_query.get_mut(closest_player)
+            && let Ok((
+                player,
+                player_rotation,
+                mut player_velocity,
+                player_mass,
+                mut player_percentage,
+                mut player_effects,
+                player_collision,
+            )) = player_query.get_mut(closest_player)
         {
+            if let Some(last_hit) = LastHitBy::new(shooter, player) {
+                commands.entity(closest_player).insert(last_hit);
+            }
             player_percentage.0 += projectile_damage.0;
-            let knockback_force =
-                (1.0 + player_percentage.0) * projectile_velocity.0 * projectile_mass.0 as f32;
-            player_velocity.0 += knockback_force / player_mass.0 as f32;
+            let knockback = knockback.map_or_else(default, |knockback| knockback.0);
+            player_velocity.0 += knockback.velocity(&KnockbackHit {
+                impulse: projectile_velocity.0
+                    * projectile_mass.0 as f32
+                    * player_effects.knockback_multiplier(),
+                up: Vec2::from_angle(player_rotation.0).rotate(Vec2::Y),
+                percentage: player_percentage.0,
+                damage: projectile_damage.0,
+                mass: player_mass.0 as f32,
+            });
 
             if player_collision.is_some_and(|collision| collision.collides) {
-                commands.entity(closest_player).insert(Stunned);
+                player_effects.apply(&StatusEffectConfig::HIT_STUN);
+            }
+            if let Some(hitstun) = knockback.hitstun(time.delta_secs()) {
+                player_effects.apply(&hitstun);
+            }
+            if let Some(inflicts) = inflicts {
+                player_effects.apply_all(&inflicts.0);
             }
         }
 
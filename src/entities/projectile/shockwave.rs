@@ -0,0 +1,191 @@
+use bevy::{
+    ecs::entity::{EntityMapper, MapEntities},
+    prelude::*,
+};
+
+use super::{Damage, Knockback, KnockbackHit, Projectile};
+use crate::{
+    core::{
+        collision::CollisionShape,
+        gravity::{self, Mass},
+        physics::{Position, Rotation, Velocity},
+    },
+    entities::{
+        player::{
+            Percentage, Player,
+            status::{Inflicts, StatusEffects},
+        },
+        satellite::{
+            grabber::GrabbedConstraint,
+            slingshot::{Orbited, WasInsideOrbitZone},
+        },
+    },
+    level::{
+        kills::{LastHitBy, Shooter},
+        stocks::Invulnerable,
+    },
+};
+
+const SHOCKWAVE_COLOR: Color = Color::srgb(0.6, 0.9, 1.);
+
+#[derive(serde::Deserialize, Clone, Copy, Debug, Reflect)]
+pub struct ShockWaveStats {
+    /// Ring expansion speed, in pixels per second
+    pub speed: f32,
+    /// Radius at which the ring vanishes
+    pub max_radius: f32,
+    /// Knockback dealt to players, akin to `mass * velocity` of a bullet
+    pub impulse: f32,
+    /// Outward velocity added to loose projectiles
+    pub deflection: f32,
+}
+
+/// Ring expanding from its `Position`, hitting everything it crosses once
+#[derive(Component, Clone, Debug, Reflect)]
+#[require(Name::new("ShockWave"))]
+pub struct ShockWave {
+    pub stats: ShockWaveStats,
+    pub radius: f32,
+    /// Entities already hit, starting with the shooter. Remapped on rollback.
+    pub hit: Vec<Entity>,
+}
+
+impl MapEntities for ShockWave {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.hit.map_entities(entity_mapper);
+    }
+}
+
+impl ShockWave {
+    pub fn new(stats: ShockWaveStats, shooter: Entity) -> Self {
+        Self {
+            stats,
+            radius: 0.,
+            hit: vec![shooter],
+        }
+    }
+}
+
+/// Runs before players get moved, and after satellites so that it can knock players off them
+pub(super) fn expand_shockwaves(
+    mut commands: Commands,
+    mut shockwave_query: Query<(
+        Entity,
+        &Position,
+        &Damage,
+        &mut ShockWave,
+        Option<&Shooter>,
+        Option<&Inflicts>,
+        Option<&Knockback>,
+    )>,
+    mut player_query: Query<
+        (
+            Entity,
+            &Player,
+            &Position,
+            &Rotation,
+            &CollisionShape,
+            &mut Velocity,
+            &Mass,
+            &mut Percentage,
+            &mut StatusEffects,
+            Has<Orbited>,
+            Has<GrabbedConstraint>,
+        ),
+        (With<Player>, Without<Projectile>, Without<Invulnerable>),
+    >,
+    mut projectile_query: Query<(Entity, &Position, &mut Velocity), With<Projectile>>,
+    time: Res<Time>,
+) {
+    // Need to sort for determinism, as percentages add up
+    let mut shockwaves = shockwave_query.iter_mut().collect::<Vec<_>>();
+    shockwaves.sort_by_key(|(_, position, ..)| *position);
+
+    for (entity, center, damage, mut shockwave, shooter, inflicts, knockback) in shockwaves {
+        let knockback = knockback.map_or_else(default, |knockback| knockback.0);
+        shockwave.radius = (shockwave.radius + shockwave.stats.speed * time.delta_secs())
+            .min(shockwave.stats.max_radius);
+
+        for (
+            player_entity,
+            player,
+            position,
+            rotation,
+            shape,
+            mut velocity,
+            mass,
+            mut percentage,
+            mut effects,
+            is_orbited,
+            is_grabbed,
+        ) in player_query.iter_mut()
+        {
+            let offset = position.0 - center.0;
+            if shockwave.hit.contains(&player_entity)
+                || offset.length() - shape.bounding_radius() > shockwave.radius
+            {
+                continue;
+            }
+            shockwave.hit.push(player_entity);
+
+            if let Some(last_hit) = LastHitBy::new(shooter, player) {
+                commands.entity(player_entity).insert(last_hit);
+            }
+
+            percentage.0 += damage.0;
+            velocity.0 += knockback.velocity(&KnockbackHit {
+                impulse: offset.normalize_or_zero()
+                    * shockwave.stats.impulse
+                    * effects.knockback_multiplier(),
+                up: Vec2::from_angle(rotation.0).rotate(Vec2::Y),
+                percentage: percentage.0,
+                damage: damage.0,
+                mass: mass.0 as f32,
+            });
+            if let Some(hitstun) = knockback.hitstun(time.delta_secs()) {
+                effects.apply(&hitstun);
+            }
+            if let Some(inflicts) = inflicts {
+                effects.apply_all(&inflicts.0);
+            }
+
+            if is_orbited {
+                // Keeps the slingshot from catching it right back
+                commands
+                    .entity(player_entity)
+                    .remove::<Orbited>()
+                    .insert(WasInsideOrbitZone);
+            }
+            if is_grabbed {
+                commands
+                    .entity(player_entity)
+                    .remove::<(GrabbedConstraint, gravity::Static)>();
+            }
+        }
+
+        for (projectile, position, mut velocity) in projectile_query.iter_mut() {
+            let offset = position.0 - center.0;
+            if shockwave.hit.contains(&projectile) || offset.length() > shockwave.radius {
+                continue;
+            }
+            shockwave.hit.push(projectile);
+
+            velocity.0 += offset.normalize_or_zero() * shockwave.stats.deflection;
+        }
+
+        if shockwave.radius >= shockwave.stats.max_radius {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+pub(super) fn draw_shockwaves(mut gizmos: Gizmos, query: Query<(&Position, &ShockWave)>) {
+    for (position, shockwave) in query.iter() {
+        let progress = shockwave.radius / shockwave.stats.max_radius;
+        gizmos.circle_2d(
+            position.0,
+            shockwave.radius,
+            SHOCKWAVE_COLOR.with_alpha(1. - progress),
+        );
+    }
+}
@@ -5,6 +5,10 @@ use bevy_asset_loader::prelude::*;
 pub struct SatelliteAssets {
     #[asset(path = "config/config.satellites.ron")]
     pub config: Handle<SatelliteConfig>,
+}
+
+#[derive(AssetCollection, Resource)]
+pub struct SatelliteSpriteAssets {
     #[asset(path = "img/satellites/working_graviton.png")]
     pub working_graviton: Handle<Image>,
     #[asset(path = "img/satellites/destroyed_graviton.png")]
@@ -13,6 +17,17 @@ pub struct SatelliteAssets {
     pub working_bumper: Handle<Image>,
     #[asset(path = "img/satellites/working_grabber.png")]
     pub working_grabber: Handle<Image>,
+    /// Slingshot cord animation
+    #[asset(
+        paths(
+            "img/satellites/slingshot/rope_1.png",
+            "img/satellites/slingshot/rope_2.png",
+            "img/satellites/slingshot/rope_3.png",
+            "img/satellites/slingshot/rope_4.png"
+        ),
+        collection(typed)
+    )]
+    pub rope_frames: Vec<Handle<Image>>,
 }
 
 #[derive(serde::Deserialize, Asset, TypePath)]
@@ -6,18 +6,15 @@ use super::SatelliteSet;
 use super::assets::{SatelliteAssets, SatelliteConfig};
 use crate::core::gravity;
 use crate::core::inputs::{PlayerAction, PlayerActionState};
-use crate::core::physics::{Position, Velocity};
+use crate::core::physics::{Acceleration, ForceSet, PhysicsStep, Position, Velocity};
 use crate::entities::player::Player;
 
+const INTERACT_PROMPT: &str = "Press E to hang";
+
 #[derive(Component)]
 #[require(Name::new("Grabber"))]
 pub struct Grabber;
 
-#[derive(Component)]
-pub struct ShowInteractPrompt {
-    pub message: String,
-}
-
 #[derive(Component, Clone, Reflect)]
 pub struct NearbyGrabber(pub Entity);
 
@@ -53,13 +50,11 @@ impl Plugin for GrabberPlugin {
                     detect_player_entry,
                     handle_grabber_interaction,
                     update_grabbed_players,
-                    cleanup_grabber_ropes,
-                    update_grabber_ropes,
                 )
                     .chain()
                     .in_set(SatelliteSet::Grabber),
-            );
-        app.add_systems(Update, (display_interact_prompt, remove_interact_prompt));
+            )
+            .add_systems(PhysicsStep, apply_grabber_springs.in_set(ForceSet));
     }
 }
 
@@ -89,30 +84,24 @@ fn detect_player_entry(
             Some((grabber_entity, _)) => {
                 commands
                     .entity(player_entity)
-                    .insert(ShowInteractPrompt {
-                        message: "Press E to hang".to_string(),
-                    })
                     .insert(NearbyGrabber(grabber_entity));
             }
             None => {
-                commands
-                    .entity(player_entity)
-                    .remove::<ShowInteractPrompt>()
-                    .remove::<NearbyGrabber>();
+                commands.entity(player_entity).remove::<NearbyGrabber>();
             }
         }
     }
 }
 
-fn display_interact_prompt(
+pub(super) fn display_interact_prompt(
     mut commands: Commands,
-    player_query: Query<(Entity, &Player, &ShowInteractPrompt, &NearbyGrabber)>,
+    player_query: Query<(Entity, &Player, &NearbyGrabber)>,
     grabber_query: Query<&Transform, With<Grabber>>,
     prompt_query: Query<&PlayerPrompt>,
     asset_server: Res<AssetServer>,
     local_players: Option<Res<LocalPlayers>>,
 ) {
-    for (player_entity, player, prompt, nearby_grabber) in player_query.iter() {
+    for (player_entity, player, nearby_grabber) in player_query.iter() {
         if local_players
             .as_ref()
             .is_some_and(|local| !local.0.contains(&player.handle))
@@ -125,7 +114,7 @@ fn display_interact_prompt(
         {
             let font = asset_server.load("fonts/FiraSans-Bold.ttf");
             commands.spawn((
-                Text2d::new(prompt.message.clone()),
+                Text2d::new(INTERACT_PROMPT),
                 TextFont {
                     font,
                     font_size: 30.0,
@@ -148,13 +137,13 @@ fn display_interact_prompt(
     }
 }
 
-fn remove_interact_prompt(
+pub(super) fn remove_interact_prompt(
     mut commands: Commands,
     prompt_query: Query<(Entity, &PlayerPrompt)>,
-    player_query: Query<&ShowInteractPrompt, With<Player>>,
+    player_query: Query<(), (With<Player>, With<NearbyGrabber>)>,
 ) {
     for (prompt_entity, prompt) in prompt_query.iter() {
-        if player_query.get(prompt.player).is_err() {
+        if !player_query.contains(prompt.player) {
             commands.entity(prompt_entity).despawn();
         }
     }
@@ -162,8 +151,6 @@ fn remove_interact_prompt(
 
 fn handle_grabber_interaction(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
     mut player_query: Query<
         (
             Entity,
@@ -219,19 +206,6 @@ fn handle_grabber_interaction(
                     },
                     gravity::Static,
                 ));
-
-                let mesh = meshes.add(Rectangle::new(4.0, 1.0));
-                let material = materials.add(Color::srgb(0.0, 0.0, 1.0));
-                commands.spawn((
-                    Mesh2d(mesh),
-                    MeshMaterial2d(material),
-                    Transform::from_translation(((center + pos) / 2.0).extend(1.0))
-                        .looking_at((pos - center).extend(0.0), Vec3::Y),
-                    GrabberRope {
-                        player: player_entity,
-                        grabber: nearby.0,
-                    },
-                ));
             }
         } else if !is_pressed && is_grabbed {
             commands
@@ -243,24 +217,37 @@ fn handle_grabber_interaction(
 
 fn update_grabbed_players(
     mut commands: Commands,
-    mut query: Query<(Entity, &mut Position, &mut Velocity, &GrabbedConstraint), With<Player>>,
-    anchor_query: Query<&Position, (With<Grabber>, Without<Player>)>,
+    query: Query<(Entity, &Velocity), (With<Player>, With<GrabbedConstraint>)>,
     assets: Res<SatelliteAssets>,
     configs: Res<Assets<SatelliteConfig>>,
-    time: Res<Time>,
 ) {
     let Some(config) = configs.get(&assets.config) else {
         warn!("Satellite config not loaded yet");
         return;
     };
 
-    for (entity, position, mut velocity, constraint) in query.iter_mut() {
+    for (entity, velocity) in query.iter() {
         if velocity.0.length() > config.grabber.max_speed {
             commands
                 .entity(entity)
                 .remove::<(GrabbedConstraint, gravity::Static)>();
-            continue;
         }
+    }
+}
+
+/// Spring pulling grabbed players towards their anchor, re-evaluated on each physics sub-step
+fn apply_grabber_springs(
+    mut query: Query<(&Position, &Velocity, &mut Acceleration, &GrabbedConstraint), With<Player>>,
+    anchor_query: Query<&Position, (With<Grabber>, Without<Player>)>,
+    assets: Res<SatelliteAssets>,
+    configs: Res<Assets<SatelliteConfig>>,
+) {
+    let Some(config) = configs.get(&assets.config) else {
+        warn!("Satellite config not loaded yet");
+        return;
+    };
+
+    for (position, velocity, mut acceleration, constraint) in query.iter_mut() {
         if let Ok(anchor_pos) = anchor_query.get(constraint.anchor) {
             let displacement = position.0 - anchor_pos.0;
             let distance = displacement.length();
@@ -271,15 +258,37 @@ fn update_grabbed_players(
 
             let total_force_magnitude = force_magnitude + damping_force;
 
-            let force = direction * total_force_magnitude;
+            acceleration.0 += direction * total_force_magnitude;
+        }
+    }
+}
 
-            velocity.0 += force * time.delta_secs();
+/// Spawns a rope for each grabbed player missing one, placed by `update_grabber_ropes`
+pub(super) fn spawn_grabber_ropes(
+    mut commands: Commands,
+    player_query: Query<(Entity, &GrabbedConstraint), With<Player>>,
+    rope_query: Query<&GrabberRope>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for (player_entity, constraint) in player_query.iter() {
+        if rope_query.iter().any(|rope| rope.player == player_entity) {
+            continue;
         }
+
+        commands.spawn((
+            Mesh2d(meshes.add(Rectangle::new(4.0, 1.0))),
+            MeshMaterial2d(materials.add(Color::srgb(0.0, 0.0, 1.0))),
+            GrabberRope {
+                player: player_entity,
+                grabber: constraint.anchor,
+            },
+        ));
     }
 }
 
 #[allow(clippy::disallowed_methods)] // Visual doesn't need determinism
-fn update_grabber_ropes(
+pub(super) fn update_grabber_ropes(
     mut commands: Commands,
     rope_query: Query<(Entity, &GrabberRope)>,
     player_query: Query<&Transform, With<Player>>,
@@ -308,7 +317,7 @@ fn update_grabber_ropes(
     }
 }
 
-fn cleanup_grabber_ropes(
+pub(super) fn cleanup_grabber_ropes(
     mut commands: Commands,
     rope_query: Query<(Entity, &GrabberRope)>,
     player_query: Query<Has<GrabbedConstraint>, With<Player>>,
@@ -9,11 +9,12 @@ use crate::entities::satellite::Satellite;
 
 use bevy_ggrs::GgrsSchedule;
 
-use super::{SatelliteAssets, SatelliteConfig, SatelliteSet};
+use super::{SatelliteAssets, SatelliteConfig, SatelliteSet, assets::SatelliteSpriteAssets};
 
 #[derive(Component, Debug, Reflect, Clone)]
 #[reflect(Component)]
 pub struct Orbited {
+    pub slingshot: Entity,
     pub center: Vec2,
     pub time_left: f32,
     pub initial_speed: f32,
@@ -22,7 +23,7 @@ pub struct Orbited {
     pub angle: f32,
 }
 
-#[derive(Component)]
+#[derive(Component, Clone, Debug, Reflect)]
 pub struct OrbitCooldown {
     pub timer: Timer,
 }
@@ -45,9 +46,6 @@ pub struct EjectionArrow {
 #[derive(Component)]
 pub struct SlingshotCord;
 
-#[derive(Resource)]
-pub struct SlingshotCordFrames(pub Vec<Handle<Image>>);
-
 #[derive(Component)]
 pub struct SlingshotCordAnimation {
     pub timer: Timer,
@@ -58,28 +56,27 @@ pub struct SlingshotCordTarget {
     pub target: Entity,
 }
 
-#[derive(Component)]
+#[derive(Component, Clone, Copy, Debug, Reflect)]
 pub struct WasInsideOrbitZone;
 
 pub struct SlingshotPlugin;
 impl Plugin for SlingshotPlugin {
     fn build(&self, app: &mut App) {
-        app.register_type::<Orbited>().add_systems(
-            GgrsSchedule,
-            (
-                detect_player_orbit_entry,
-                update_orbiting_players,
-                update_orbit_cooldowns,
-                mark_players_in_orbit_zone,
-                cleanup_orbit_zone_flags,
-                update_ejection_arrows,
-                load_slingcord_frames,
-                animate_slingshot_cord,
-                update_slingcord_transform,
-            )
-                .chain()
-                .in_set(SatelliteSet::Slingshot),
-        );
+        app.register_type::<Orbited>()
+            .register_type::<OrbitCooldown>()
+            .register_type::<WasInsideOrbitZone>()
+            .add_systems(
+                GgrsSchedule,
+                (
+                    detect_player_orbit_entry,
+                    update_orbiting_players,
+                    update_orbit_cooldowns,
+                    mark_players_in_orbit_zone,
+                    cleanup_orbit_zone_flags,
+                )
+                    .chain()
+                    .in_set(SatelliteSet::Slingshot),
+            );
     }
 }
 
@@ -95,9 +92,6 @@ fn detect_player_orbit_entry(
     >,
     assets: Res<SatelliteAssets>,
     configs: Res<Assets<SatelliteConfig>>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
-    asset_server: Res<AssetServer>,
 ) {
     let Some(config) = configs.get(&assets.config) else {
         warn!("Satellite config not loaded yet");
@@ -122,6 +116,7 @@ fn detect_player_orbit_entry(
 
             if distance < orbit_radius {
                 commands.entity(player_entity).insert(Orbited {
+                    slingshot: slingshot_entity,
                     center: slingshot_pos.0,
                     time_left: orbit_duration,
                     initial_speed: velocity.length(),
@@ -129,51 +124,6 @@ fn detect_player_orbit_entry(
                     elapsed: 0.0,
                     angle: atan2(offset.y, offset.x),
                 });
-                let center = slingshot_pos.0;
-                let direction = (entry_pos - center).normalize();
-                let arrow_distance = (entry_pos - center).length() + 30.0;
-                let arrow_pos = center + direction * arrow_distance;
-                let arrow_angle = atan2(direction.y, direction.x);
-
-                // Créer les deux meshes
-                let shaft = meshes.add(Rectangle::new(12.0, 48.0));
-                let material = materials.add(Color::srgb(1.0, 0.8, 0.0));
-
-                // Corps de flèche (rotation +90°)
-                commands.spawn((
-                    Mesh2d(shaft),
-                    MeshMaterial2d(material.clone()),
-                    Transform {
-                        translation: arrow_pos.extend(2.0),
-                        rotation: Quat::from_rotation_z(arrow_angle + std::f32::consts::FRAC_PI_2),
-                        ..default()
-                    },
-                    GlobalTransform::default(),
-                    EjectionArrow {
-                        player: player_entity,
-                    },
-                ));
-
-                commands.entity(slingshot_entity).with_children(|parent| {
-                    parent.spawn((
-                        Sprite {
-                            image: asset_server.load("img/satellites/slingshot/rope_1.png"),
-                            ..default()
-                        },
-                        Transform {
-                            translation: Vec3::new(0.0, 0.0, 1.0),
-                            ..default()
-                        },
-                        GlobalTransform::default(),
-                        SlingshotCord,
-                        SlingshotCordTarget {
-                            target: player_entity,
-                        },
-                        SlingshotCordAnimation {
-                            timer: Timer::from_seconds(1.0, TimerMode::Once),
-                        },
-                    ));
-                });
                 break;
             }
         }
@@ -183,7 +133,6 @@ fn detect_player_orbit_entry(
     }
 }
 
-// Retarde le despawn de la corde jusqu'à ce que l'animation soit terminée
 fn update_orbiting_players(
     mut commands: Commands,
     mut query: Query<
@@ -196,8 +145,6 @@ fn update_orbiting_players(
         ),
         Without<Slingshot>,
     >,
-    slingshot_query: Query<(Entity, &Position), (With<Satellite>, With<Slingshot>)>,
-    cord_query: Query<(Entity, &SlingshotCordTarget, &SlingshotCordAnimation)>,
     assets: Res<SatelliteAssets>,
     configs: Res<Assets<SatelliteConfig>>,
     time: Res<Time>,
@@ -243,39 +190,123 @@ fn update_orbiting_players(
             velocity.0 = eject_dir * orbited.initial_speed;
             commands.entity(entity).remove::<Orbited>();
 
-            let animation_duration = 1.0 / orbited.initial_speed * 2.0; // Ajuste la durée de l'animation
-            commands.entity(entity).with_children(|parent| {
-                parent.spawn((SlingshotCordAnimation {
-                    timer: Timer::from_seconds(animation_duration, TimerMode::Once),
-                },));
+            // Cooldown du slingshot
+            commands.entity(orbited.slingshot).insert(OrbitCooldown {
+                timer: Timer::from_seconds(config.slingshot.orbit_cooldown, TimerMode::Once),
             });
+        }
+    }
+}
+
+fn update_orbit_cooldowns(mut query: Query<&mut OrbitCooldown, With<Slingshot>>, time: Res<Time>) {
+    for mut cooldown in query.iter_mut() {
+        cooldown.timer.tick(time.delta());
+    }
+}
+
+fn mark_players_in_orbit_zone(
+    mut commands: Commands,
+    slingshot_query: Query<(&Position, Option<&OrbitCooldown>), (With<Slingshot>, With<Satellite>)>,
+    player_query: Query<(Entity, &Position), With<Player>>,
+    configs: Res<Assets<SatelliteConfig>>,
+    assets: Res<SatelliteAssets>,
+) {
+    let Some(config) = configs.get(&assets.config) else {
+        return;
+    };
+    let radius = config.slingshot.orbit_radius;
 
-            // **Ne pas despawn immédiatement la corde ici** !
-            // Retarde le despawn à la fin de l'animation
-            for (cord_entity, cord_target, animation) in cord_query.iter() {
-                if cord_target.target == entity {
-                    // Si l'animation est terminée, on despawn la corde
-                    if animation.timer.finished() {
-                        commands.entity(cord_entity).despawn();
-                    }
+    for (player_entity, player_pos) in player_query.iter() {
+        for (slingshot_pos, cooldown) in slingshot_query.iter() {
+            if let Some(cd) = cooldown
+                && cd.timer.finished()
+            {
+                let dist = player_pos.0.distance(slingshot_pos.0);
+                if dist < radius {
+                    commands.entity(player_entity).insert(WasInsideOrbitZone);
                 }
             }
+        }
+    }
+}
 
-            // Cooldown du slingshot
-            if let Some((slingshot_entity, _)) = slingshot_query.iter().min_by(|(_, a), (_, b)| {
-                a.0.distance_squared(position.0)
-                    .partial_cmp(&b.0.distance_squared(position.0))
-                    .unwrap()
-            }) {
-                commands.entity(slingshot_entity).insert(OrbitCooldown {
-                    timer: Timer::from_seconds(config.slingshot.orbit_cooldown, TimerMode::Once),
-                });
+fn cleanup_orbit_zone_flags(
+    mut commands: Commands,
+    player_query: Query<
+        (Entity, &Position),
+        (With<Player>, With<WasInsideOrbitZone>, Without<Orbited>),
+    >,
+    slingshot_query: Query<&Position, (With<Slingshot>, With<Satellite>)>,
+    configs: Res<Assets<SatelliteConfig>>,
+    assets: Res<SatelliteAssets>,
+) {
+    let Some(config) = configs.get(&assets.config) else {
+        return;
+    };
+    let orbit_radius = config.slingshot.orbit_radius;
+
+    for (player_entity, player_pos) in player_query.iter() {
+        let mut still_inside = false;
+        for slingshot_pos in slingshot_query.iter() {
+            if player_pos.0.distance(slingshot_pos.0) < orbit_radius {
+                still_inside = true;
+                break;
             }
         }
+
+        if !still_inside {
+            commands
+                .entity(player_entity)
+                .remove::<WasInsideOrbitZone>();
+        }
     }
 }
 
-fn update_ejection_arrows(
+/// Spawns an ejection arrow and a cord for each orbiting player missing them
+pub(super) fn spawn_orbit_visuals(
+    mut commands: Commands,
+    player_query: Query<(Entity, &Orbited), With<Player>>,
+    arrow_query: Query<&EjectionArrow>,
+    cord_query: Query<&SlingshotCordTarget>,
+    sprites: Res<SatelliteSpriteAssets>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for (player_entity, orbited) in player_query.iter() {
+        if arrow_query
+            .iter()
+            .all(|arrow| arrow.player != player_entity)
+        {
+            // Placed by `update_ejection_arrows`
+            commands.spawn((
+                Mesh2d(meshes.add(Rectangle::new(12.0, 48.0))),
+                MeshMaterial2d(materials.add(Color::srgb(1.0, 0.8, 0.0))),
+                Transform::from_xyz(0.0, 0.0, 2.0),
+                EjectionArrow {
+                    player: player_entity,
+                },
+            ));
+        }
+
+        if cord_query.iter().all(|cord| cord.target != player_entity) {
+            commands.entity(orbited.slingshot).with_children(|parent| {
+                parent.spawn((
+                    Sprite::from_image(sprites.rope_frames[0].clone()),
+                    Transform::from_xyz(0.0, 0.0, 1.0),
+                    SlingshotCord,
+                    SlingshotCordTarget {
+                        target: player_entity,
+                    },
+                    SlingshotCordAnimation {
+                        timer: Timer::from_seconds(1.0, TimerMode::Once),
+                    },
+                ));
+            });
+        }
+    }
+}
+
+pub(super) fn update_ejection_arrows(
     mut commands: Commands,
     mut arrow_query: Query<(Entity, &mut Transform, &EjectionArrow)>,
     player_query: Query<Option<&Orbited>, With<Player>>,
@@ -303,59 +334,56 @@ fn update_ejection_arrows(
     }
 }
 
-fn update_orbit_cooldowns(
-    mut cooldown_query: Query<(Entity, &mut OrbitCooldown, &Children), With<Slingshot>>,
-    mut sprite_query: Query<&mut Sprite>,
-    visual_query: Query<&SlingshotVisual>,
-    time: Res<Time>,
+/// Greys out slingshots on cooldown
+pub(super) fn update_slingshot_sprites(
+    cooldown_query: Query<(Option<&OrbitCooldown>, &SlingshotVisual, &Children), With<Slingshot>>,
+    mut sprite_query: Query<&mut Sprite, Without<SlingshotCord>>,
 ) {
-    for (entity, mut cooldown, children) in cooldown_query.iter_mut() {
-        cooldown.timer.tick(time.delta());
-
-        if let Ok(visual) = visual_query.get(entity) {
-            for child in children.iter() {
-                if let Ok(mut sprite) = sprite_query.get_mut(child) {
-                    sprite.image = if cooldown.timer.finished() {
-                        visual.active.clone()
-                    } else {
-                        visual.inactive.clone()
-                    };
-                }
+    for (cooldown, visual, children) in cooldown_query.iter() {
+        for child in children.iter() {
+            if let Ok(mut sprite) = sprite_query.get_mut(child) {
+                sprite.image = if cooldown.is_none_or(|cooldown| cooldown.timer.finished()) {
+                    visual.active.clone()
+                } else {
+                    visual.inactive.clone()
+                };
             }
         }
     }
 }
 
-fn load_slingcord_frames(asset_server: Res<AssetServer>, mut commands: Commands) {
-    let frames = vec![
-        asset_server.load("img/satellites/slingshot/rope_1.png"),
-        asset_server.load("img/satellites/slingshot/rope_2.png"),
-        asset_server.load("img/satellites/slingshot/rope_3.png"),
-        asset_server.load("img/satellites/slingshot/rope_4.png"),
-    ];
-    commands.insert_resource(SlingshotCordFrames(frames));
-}
-
-fn animate_slingshot_cord(
+pub(super) fn animate_slingshot_cord(
     time: Res<Time>,
-    frames: Res<SlingshotCordFrames>,
+    sprites: Res<SatelliteSpriteAssets>,
     mut query: Query<(&mut Sprite, &mut SlingshotCordAnimation)>,
 ) {
+    let frames = &sprites.rope_frames;
     for (mut sprite, mut animation) in query.iter_mut() {
         animation.timer.tick(time.delta());
 
         let progress = animation.timer.elapsed_secs().min(1.0);
-        let frame_index = (progress / (1.0 / frames.0.len() as f32)).floor() as usize;
+        let frame_index = (progress / (1.0 / frames.len() as f32)).floor() as usize;
 
-        if let Some(image) = frames.0.get(frame_index.min(frames.0.len() - 1)) {
+        if let Some(image) = frames.get(frame_index.min(frames.len() - 1)) {
             sprite.image = image.clone();
         }
     }
 }
 
-fn update_slingcord_transform(
-    player_query: Query<(&Position, Option<&Orbited>), With<Player>>,
-    mut cord_query: Query<(&mut Transform, &SlingshotCordTarget), With<SlingshotCord>>,
+/// Follows the ejection direction, then despawns the cord once its target left the orbit
+/// and the animation is over
+pub(super) fn update_slingcord_transform(
+    mut commands: Commands,
+    player_query: Query<Option<&Orbited>, With<Player>>,
+    mut cord_query: Query<
+        (
+            Entity,
+            &mut Transform,
+            &SlingshotCordTarget,
+            &SlingshotCordAnimation,
+        ),
+        With<SlingshotCord>,
+    >,
     assets: Res<SatelliteAssets>,
     configs: Res<Assets<SatelliteConfig>>,
 ) {
@@ -366,85 +394,32 @@ fn update_slingcord_transform(
 
     let orbit_radius = config.slingshot.orbit_radius;
 
-    for (mut transform, target) in cord_query.iter_mut() {
-        if let Ok((_, Some(orbited))) = player_query.get(target.target) {
-            let center = orbited.center;
-            let angle = orbited.angle;
-
-            // On définit un petit angle de décalage pour simuler les deux attaches
-            let delta_angle = 0.5; // ~28.6° (ajuste si nécessaire)
-
-            let left_anchor = center + Vec2::from_angle(angle + delta_angle) * orbit_radius;
-            let right_anchor = center + Vec2::from_angle(angle - delta_angle) * orbit_radius;
-
-            // Point milieu entre les deux attaches
-            let midpoint = (left_anchor + right_anchor) * 0.5;
-            let direction = right_anchor - left_anchor;
-            let distance = direction.length();
-            let angle_z = atan2(direction.y, direction.x);
+    for (cord_entity, mut transform, target, animation) in cord_query.iter_mut() {
+        let Ok(Some(orbited)) = player_query.get(target.target) else {
+            if animation.timer.finished() {
+                commands.entity(cord_entity).despawn();
+            }
+            continue;
+        };
 
-            // Position relative à son parent (le satellite)
-            transform.translation = (midpoint - center).extend(1.0);
-            transform.scale = Vec3::new(distance / 20.0, 8.0, 1.0); // ajuste les diviseurs à ton sprite
-            transform.rotation = Quat::from_rotation_z(angle_z);
-        }
-    }
-}
+        let center = orbited.center;
+        let angle = orbited.angle;
 
-fn mark_players_in_orbit_zone(
-    mut commands: Commands,
-    slingshot_query: Query<(&Position, Option<&OrbitCooldown>), (With<Slingshot>, With<Satellite>)>,
-    player_query: Query<(Entity, &Position), With<Player>>,
-    configs: Res<Assets<SatelliteConfig>>,
-    assets: Res<SatelliteAssets>,
-) {
-    let Some(config) = configs.get(&assets.config) else {
-        return;
-    };
-    let radius = config.slingshot.orbit_radius;
-
-    for (player_entity, player_pos) in player_query.iter() {
-        for (slingshot_pos, cooldown) in slingshot_query.iter() {
-            if let Some(cd) = cooldown
-                && cd.timer.finished()
-            {
-                let dist = player_pos.0.distance(slingshot_pos.0);
-                if dist < radius {
-                    commands.entity(player_entity).insert(WasInsideOrbitZone);
-                }
-            }
-        }
-    }
-}
+        // On définit un petit angle de décalage pour simuler les deux attaches
+        let delta_angle = 0.5; // ~28.6° (ajuste si nécessaire)
 
-fn cleanup_orbit_zone_flags(
-    mut commands: Commands,
-    player_query: Query<
-        (Entity, &Position),
-        (With<Player>, With<WasInsideOrbitZone>, Without<Orbited>),
-    >,
-    slingshot_query: Query<&Position, (With<Slingshot>, With<Satellite>)>,
-    configs: Res<Assets<SatelliteConfig>>,
-    assets: Res<SatelliteAssets>,
-) {
-    let Some(config) = configs.get(&assets.config) else {
-        return;
-    };
-    let orbit_radius = config.slingshot.orbit_radius;
+        let left_anchor = center + Vec2::from_angle(angle + delta_angle) * orbit_radius;
+        let right_anchor = center + Vec2::from_angle(angle - delta_angle) * orbit_radius;
 
-    for (player_entity, player_pos) in player_query.iter() {
-        let mut still_inside = false;
-        for slingshot_pos in slingshot_query.iter() {
-            if player_pos.0.distance(slingshot_pos.0) < orbit_radius {
-                still_inside = true;
-                break;
-            }
-        }
+        // Point milieu entre les deux attaches
+        let midpoint = (left_anchor + right_anchor) * 0.5;
+        let direction = right_anchor - left_anchor;
+        let distance = direction.length();
+        let angle_z = atan2(direction.y, direction.x);
 
-        if !still_inside {
-            commands
-                .entity(player_entity)
-                .remove::<WasInsideOrbitZone>();
-        }
+        // Position relative à son parent (le satellite)
+        transform.translation = (midpoint - center).extend(1.0);
+        transform.scale = Vec3::new(distance / 20.0, 8.0, 1.0); // ajuste les diviseurs à ton sprite
+        transform.rotation = Quat::from_rotation_z(angle_z);
     }
 }
@@ -4,9 +4,50 @@ use bevy::{
         mesh::{Indices, PrimitiveTopology},
         render_resource::{AsBindGroup, ShaderRef},
     },
-    sprite::Material2d,
+    sprite::{Material2d, Material2dPlugin},
 };
 
+use super::{
+    Satellite, SatelliteAssets, SatelliteConfig,
+    assets::SatelliteSpriteAssets,
+    bumper::Bumper,
+    grabber::{self, Grabber},
+    slingshot::{self, Slingshot, SlingshotVisual},
+};
+
+pub struct SatelliteVisualsPlugin;
+impl Plugin for SatelliteVisualsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(Material2dPlugin::<OrbitMaterial>::default())
+            .add_systems(
+                Update,
+                (
+                    add_visuals.run_if(resource_exists::<SatelliteSpriteAssets>),
+                    grabber::display_interact_prompt,
+                    grabber::remove_interact_prompt,
+                    (
+                        grabber::spawn_grabber_ropes,
+                        grabber::cleanup_grabber_ropes,
+                        grabber::update_grabber_ropes,
+                    )
+                        .chain(),
+                    (
+                        slingshot::spawn_orbit_visuals,
+                        slingshot::update_ejection_arrows,
+                        slingshot::animate_slingshot_cord,
+                        slingshot::update_slingcord_transform,
+                        slingshot::update_slingshot_sprites,
+                    )
+                        .chain()
+                        .run_if(
+                            resource_exists::<SatelliteSpriteAssets>
+                                .and(resource_exists::<SatelliteAssets>),
+                        ),
+                ),
+            );
+    }
+}
+
 #[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
 pub struct OrbitMaterial {
     #[uniform(0)]
@@ -25,6 +66,78 @@ impl Material2d for OrbitMaterial {
     }
 }
 
+fn add_visuals(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<OrbitMaterial>>,
+    query: Query<(Entity, Has<Slingshot>, Has<Bumper>, Has<Grabber>), Added<Satellite>>,
+    configs: Res<Assets<SatelliteConfig>>,
+    assets: Res<SatelliteAssets>,
+    sprites: Res<SatelliteSpriteAssets>,
+) {
+    let Some(config) = configs.get(&assets.config) else {
+        warn!("Satellite config not loaded yet");
+        return;
+    };
+
+    for (satellite, is_slingshot, is_bumper, is_grabber) in query.iter() {
+        let mut entity = commands.entity(satellite);
+
+        let (image, orbit_radius, base_color) = if is_slingshot {
+            entity.insert(SlingshotVisual {
+                active: sprites.working_graviton.clone(),
+                inactive: sprites.destroyed_graviton.clone(),
+            });
+            (
+                sprites.working_graviton.clone(),
+                config.slingshot.orbit_radius + 100.0,
+                LinearRgba::new(0.0, 0.0, 1.0, 1.0),
+            )
+        } else if is_bumper {
+            (
+                sprites.working_bumper.clone(),
+                config.bumper.radius,
+                LinearRgba::new(1.0, 0.5, 0.0, 1.0),
+            )
+        } else if is_grabber {
+            (
+                sprites.working_grabber.clone(),
+                config.grabber.radius + 50.0,
+                LinearRgba::new(0.0, 1.0, 0.0, 1.0),
+            )
+        } else {
+            warn!("Satellite {satellite} has no kind");
+            continue;
+        };
+
+        let orbit_material_handle = materials.add(OrbitMaterial {
+            base_color,
+            saturation: 1.0,
+            alpha: 0.6,
+            _wasm_padding: default(),
+        });
+
+        let ring_thickness = 5.0;
+        let orbit_ring = meshes.add(generate_ring(
+            orbit_radius - ring_thickness,
+            orbit_radius,
+            64,
+        ));
+
+        entity.with_children(|parent| {
+            parent.spawn((
+                Sprite { image, ..default() },
+                Transform::from_translation(Vec3::new(130.0, 75.0, 0.0)),
+            ));
+            parent.spawn((
+                Mesh2d::from(orbit_ring),
+                MeshMaterial2d(orbit_material_handle),
+                Transform::from_translation(Vec3::new(0.0, 0.0, -1.0)),
+            ));
+        });
+    }
+}
+
 #[allow(clippy::disallowed_methods)] // Visual doesn't need determinism
 pub fn generate_ring(inner_radius: f32, outer_radius: f32, resolution: usize) -> Mesh {
     let mut positions = Vec::with_capacity(resolution * 2);
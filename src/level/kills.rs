@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+use bevy_ggrs::GgrsSchedule;
+
+use super::{limit::DeathEvent, rules::Score};
+use crate::{
+    GameState,
+    core::physics::{PhysicsSet, Position},
+    entities::player::Player,
+    network::events::RollbackEventReader,
+};
+
+/// Seconds during which the last hit gets credited for a KO
+const LAST_HIT_DURATION: f32 = 6.;
+
+/// Handle of the player who fired a projectile, or spawned a hazard
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub struct Shooter(pub usize);
+
+/// Latest opponent who hit the player, credited if the player gets KO'd before it decays
+#[derive(Component, Clone, Debug, Reflect)]
+pub struct LastHitBy {
+    pub handle: usize,
+    pub timer: Timer,
+}
+
+pub struct KillsPlugin;
+impl Plugin for KillsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Shooter>()
+            .register_type::<LastHitBy>()
+            .add_systems(
+                GgrsSchedule,
+                (
+                    tick_last_hits.before(PhysicsSet::Player),
+                    credit_kills
+                        .after(super::limit::check_outsiders)
+                        .before(super::limit::handle_player_death)
+                        .before(super::rules::check_match_end)
+                        .in_set(PhysicsSet::Collision),
+                )
+                    .run_if(in_state(GameState::InGame)),
+            );
+    }
+}
+
+impl LastHitBy {
+    /// Returns `None` when the player hit itself, which shouldn't override the last opponent hit
+    pub fn new(shooter: Option<&Shooter>, victim: &Player) -> Option<Self> {
+        let handle = shooter?.0;
+        (handle != victim.handle).then(|| Self {
+            handle,
+            timer: Timer::from_seconds(LAST_HIT_DURATION, TimerMode::Once),
+        })
+    }
+}
+
+/// Player credited for a KO: the last opponent hit, then whoever spawned the hazard, if any
+pub fn credited_killer(
+    victim: &Player,
+    last_hit: Option<&LastHitBy>,
+    hazard: Option<&Shooter>,
+) -> Option<usize> {
+    last_hit
+        .map(|last_hit| last_hit.handle)
+        .or(hazard.map(|shooter| shooter.0))
+        .filter(|handle| *handle != victim.handle)
+}
+
+fn tick_last_hits(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut LastHitBy)>,
+    time: Res<Time>,
+) {
+    for (player, mut last_hit) in query.iter_mut() {
+        last_hit.timer.tick(time.delta());
+        if last_hit.timer.finished() {
+            commands.entity(player).remove::<LastHitBy>();
+        }
+    }
+}
+
+fn credit_kills(
+    mut commands: Commands,
+    death_events: RollbackEventReader<DeathEvent>,
+    victim_query: Query<(), (With<Player>, With<Position>)>,
+    mut score_query: Query<(&Player, &mut Score)>,
+) {
+    // A player can die from multiple causes at once, only the first one counts
+    let mut credited = Vec::new();
+    for event in death_events.read() {
+        if credited.contains(&event.player) || !victim_query.contains(event.player) {
+            continue;
+        }
+        credited.push(event.player);
+        commands.entity(event.player).remove::<LastHitBy>();
+
+        if let Some(killer) = event.killer
+            && let Some((_, mut score)) = score_query
+                .iter_mut()
+                .find(|(player, _)| player.handle == killer)
+        {
+            score.0 += 1;
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 use bevy_ggrs::GgrsSchedule;
 
+use super::kills::{self, LastHitBy};
+
 use crate::{
     GameState,
     core::{
@@ -10,8 +12,13 @@ use crate::{
     entities::player::{
         Percentage, Player,
         inventory::Arsenal,
-        weapon::config::{WeaponStats, WeaponType},
+        status::StatusEffects,
+        weapon::{
+            WeaponMode, WeaponState,
+            config::{WeaponStats, WeaponType},
+        },
     },
+    network::events::{RollbackEventReader, RollbackEventWriter},
 };
 
 #[derive(Resource, Reflect)]
@@ -22,18 +29,25 @@ pub struct MapLimit {
     radius_squared: f32,
 }
 
-#[derive(Event)]
-/// Points to a Player entity
-pub struct DeathEvent(pub Entity);
+/// Ring drawn along the map limit
+#[derive(Component)]
+pub struct MapEdge;
+
+#[derive(Event, Clone, Debug)]
+pub struct DeathEvent {
+    /// Points to a Player entity
+    pub player: Entity,
+    /// Handle of the player credited with the KO
+    pub killer: Option<usize>,
+}
 
 pub struct MapLimitPlugin;
 impl Plugin for MapLimitPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<MapLimit>()
-            .add_event::<DeathEvent>()
             .add_systems(
-                Update,
-                setup.run_if(resource_added::<worldgen::WorldgenAssets>),
+                OnEnter(GameState::WorldGen),
+                setup.run_if(resource_exists::<worldgen::WorldgenAssets>),
             )
             .add_systems(
                 GgrsSchedule,
@@ -45,10 +59,15 @@ impl Plugin for MapLimitPlugin {
     }
 }
 
+pub struct MapLimitVisualsPlugin;
+impl Plugin for MapLimitVisualsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, spawn_edge.run_if(resource_added::<MapLimit>));
+    }
+}
+
 fn setup(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut color_materials: ResMut<Assets<ColorMaterial>>,
     worldgen_assets: Res<worldgen::WorldgenAssets>,
     worldgen_configs: Res<Assets<worldgen::WorldgenConfig>>,
 ) {
@@ -57,11 +76,18 @@ fn setup(
         return;
     };
 
-    let limit = MapLimit {
+    commands.insert_resource(MapLimit {
         radius: worldgen_config.edge_radius as f32,
         radius_squared: worldgen_config.edge_radius.pow(2) as f32,
-    };
+    });
+}
 
+fn spawn_edge(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut color_materials: ResMut<Assets<ColorMaterial>>,
+    limit: Res<MapLimit>,
+) {
     let edge_color = Srgba::hex("#00F9DE").unwrap();
     commands.spawn((
         Mesh2d(meshes.add(Mesh::from(Annulus::new(
@@ -70,41 +96,47 @@ fn setup(
         )))),
         MeshMaterial2d(color_materials.add(ColorMaterial::from_color(edge_color))),
         Transform::default(),
+        MapEdge,
     ));
-
-    commands.insert_resource(limit);
 }
 
-fn handle_player_death(
+pub(super) fn handle_player_death(
     mut commands: Commands,
-    mut death_events: EventReader<DeathEvent>,
+    death_events: RollbackEventReader<DeathEvent>,
     query: Query<&Arsenal, With<Player>>,
+    mut weapon_query: Query<(&WeaponStats, &mut WeaponState, &mut WeaponMode)>,
 ) -> Result {
-    for DeathEvent(player) in death_events.read() {
+    for DeathEvent { player, .. } in death_events.read() {
         // Refresh player
         commands
             .entity(*player)
             .remove::<Position>()
-            .insert(Percentage::default());
+            .insert((Percentage::default(), StatusEffects::default()));
         // Refresh weapons
         let arsenal = query.get(*player)?;
         for (_, weapon) in arsenal.0.iter() {
-            commands.entity(*weapon).remove::<WeaponStats>();
+            if let Ok((stats, mut state, mut mode)) = weapon_query.get_mut(*weapon) {
+                *state = WeaponState::new(stats);
+                *mode = WeaponMode::Idle;
+            }
         }
     }
     Ok(())
 }
 
-fn check_outsiders(
+pub(crate) fn check_outsiders(
     mut commands: Commands,
-    mut death_events: EventWriter<DeathEvent>,
-    query: Query<(Entity, &Position, Has<Player>), Without<WeaponType>>,
+    mut death_events: RollbackEventWriter<DeathEvent>,
+    query: Query<(Entity, &Position, Option<&Player>, Option<&LastHitBy>), Without<WeaponType>>,
     limit: Res<MapLimit>,
 ) {
-    for (entity, position, is_player) in query.iter() {
+    for (entity, position, player, last_hit) in query.iter() {
         if position.length_squared() > limit.radius_squared {
-            if is_player {
-                death_events.write(DeathEvent(entity));
+            if let Some(player) = player {
+                death_events.write(DeathEvent {
+                    player: entity,
+                    killer: kills::credited_killer(player, last_hit, None),
+                });
             } else {
                 commands.entity(entity).despawn();
             }
@@ -1,14 +1,29 @@
 use bevy::prelude::{App, Plugin};
 
+pub mod kills;
 pub mod limit;
+pub mod rules;
 pub mod save;
 pub mod spawn;
+pub mod stocks;
 
+/// Level simulation plugins
 pub struct LevelPlugins;
 impl Plugin for LevelPlugins {
     fn build(&self, app: &mut App) {
         app.add_plugins(limit::MapLimitPlugin)
             .add_plugins(save::LevelSavePlugin)
-            .add_plugins(spawn::MapSpawnPlugin);
+            .add_plugins(spawn::MapSpawnPlugin)
+            .add_plugins(stocks::StocksPlugin)
+            .add_plugins(rules::MatchRulesPlugin)
+            .add_plugins(kills::KillsPlugin);
+    }
+}
+
+/// Level rendering plugins
+pub struct LevelVisualsPlugins;
+impl Plugin for LevelVisualsPlugins {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(limit::MapLimitVisualsPlugin);
     }
 }
@@ -0,0 +1,290 @@
+use std::cmp::Reverse;
+
+use bevy::prelude::*;
+use bevy_ggrs::{GgrsSchedule, Rollback};
+
+use super::{
+    limit::{MapEdge, MapLimit},
+    stocks::{Eliminated, Stocks},
+};
+use crate::{
+    GameState,
+    core::physics::{PhysicsSet, Position},
+    entities::{
+        planet::Planet,
+        player::Player,
+        satellite::{Satellite, grabber::GrabberRope, slingshot::EjectionArrow},
+    },
+    network::events::RollbackEventWriter,
+};
+
+/// How a match is won
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct MatchRules {
+    /// Seconds before the match ends, `None` for no limit
+    pub time_limit: Option<f32>,
+    /// Lives each player starts with
+    pub stocks: u32,
+    /// KOs needed to win, `None` to only play on stocks
+    pub score_to_win: Option<u32>,
+    /// When time runs out, tied leaders keep fighting on their last life instead of drawing
+    pub sudden_death: bool,
+}
+
+/// KOs credited to the player
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub struct Score(pub u32);
+
+/// Rollback state of the ongoing match
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+pub struct MatchClock {
+    /// Seconds since the match started
+    pub elapsed: f32,
+    pub sudden_death: bool,
+    /// Set once the match got decided, results are shown when that frame gets confirmed
+    pub over: bool,
+}
+
+/// Written once when the match gets decided
+#[derive(Event, Clone, Debug)]
+pub struct MatchEndedEvent {
+    pub results: MatchResults,
+}
+
+/// Written once when tied leaders start fighting on their last life
+#[derive(Event, Clone, Debug)]
+pub struct SuddenDeathEvent;
+
+/// Final standings, available in `GameState::Results`
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct MatchResults {
+    /// Sorted by rank
+    pub placements: Vec<Placement>,
+}
+
+#[derive(Clone, Debug, Reflect)]
+pub struct Placement {
+    /// Starts at 1, tied players share the same rank
+    pub rank: usize,
+    pub handle: usize,
+    pub stocks: u32,
+    pub score: u32,
+}
+
+pub struct MatchRulesPlugin;
+impl Plugin for MatchRulesPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<MatchRules>()
+            .register_type::<Score>()
+            .register_type::<MatchClock>()
+            .register_type::<MatchResults>()
+            .init_resource::<MatchRules>()
+            .register_required_components::<Player, Score>()
+            .add_systems(OnEnter(GameState::InGame), start_clock)
+            .add_systems(OnExit(GameState::Results), teardown_match)
+            .add_systems(
+                Update,
+                (
+                    announce_sudden_death,
+                    show_results.run_if(in_state(GameState::InGame)),
+                ),
+            )
+            .add_systems(
+                GgrsSchedule,
+                (
+                    tick_clock.before(PhysicsSet::Player),
+                    check_match_end
+                        .after(super::stocks::handle_player_death)
+                        .after(PhysicsSet::Collision),
+                )
+                    .run_if(in_state(GameState::InGame).and(resource_exists::<MatchClock>)),
+            );
+    }
+}
+
+impl MatchResults {
+    /// `None` on draws
+    pub fn winner(&self) -> Option<usize> {
+        match self.placements.as_slice() {
+            [first, second, ..] if second.rank == first.rank => None,
+            [first, ..] => Some(first.handle),
+            [] => None,
+        }
+    }
+}
+
+fn start_clock(mut commands: Commands) {
+    commands.insert_resource(MatchClock::default());
+}
+
+fn tick_clock(mut clock: ResMut<MatchClock>, time: Res<Time>) {
+    clock.elapsed += time.delta_secs();
+}
+
+pub(super) fn check_match_end(
+    mut commands: Commands,
+    mut match_end_events: RollbackEventWriter<MatchEndedEvent>,
+    mut sudden_death_events: RollbackEventWriter<SuddenDeathEvent>,
+    mut clock: ResMut<MatchClock>,
+    mut query: Query<(Entity, &Player, &mut Stocks, &Score, Has<Eliminated>)>,
+    rules: Res<MatchRules>,
+) {
+    if clock.over {
+        return;
+    }
+
+    // Alive players first, then the most stocks, then the highest score
+    let standing = |stocks: &Stocks, score: &Score, is_eliminated: bool| {
+        (is_eliminated, Reverse(stocks.0), Reverse(score.0))
+    };
+
+    let mut players = query
+        .iter()
+        .map(|(entity, player, stocks, score, is_eliminated)| {
+            (
+                standing(stocks, score, is_eliminated),
+                entity,
+                player.handle,
+            )
+        })
+        .collect::<Vec<_>>();
+    // Sort by handle for determinism
+    players.sort_by_key(|(standing, _, handle)| (*standing, *handle));
+
+    // Nobody to play against, e.g. single player dev sessions
+    if players.len() < 2 {
+        return;
+    }
+
+    let alive = players
+        .iter()
+        .filter(|((is_eliminated, ..), ..)| !is_eliminated)
+        .count();
+    let time_is_up = rules
+        .time_limit
+        .is_some_and(|time_limit| clock.elapsed >= time_limit);
+
+    let is_over = if alive <= 1 {
+        true
+    } else if let Some(score_to_win) = rules.score_to_win
+        && players
+            .iter()
+            .any(|((.., Reverse(score)), ..)| *score >= score_to_win)
+    {
+        true
+    } else if time_is_up && !clock.sudden_death {
+        let leaders = players
+            .iter()
+            .take_while(|(standing, ..)| Some(standing) == players.first().map(|(s, ..)| s))
+            .map(|(_, entity, _)| *entity)
+            .collect::<Vec<_>>();
+
+        if leaders.len() > 1 && rules.sudden_death {
+            sudden_death_events.write(SuddenDeathEvent);
+            clock.sudden_death = true;
+            for (_, entity, _) in players.iter() {
+                if leaders.contains(entity) {
+                    if let Ok((.., mut stocks, _, _)) = query.get_mut(*entity) {
+                        stocks.0 = 1;
+                    }
+                } else {
+                    commands
+                        .entity(*entity)
+                        .remove::<Position>()
+                        .insert(Eliminated);
+                }
+            }
+            false
+        } else {
+            true
+        }
+    } else {
+        false
+    };
+
+    if !is_over {
+        return;
+    }
+
+    let placements = players
+        .iter()
+        .map(|(standing, _, handle)| {
+            let (_, Reverse(stocks), Reverse(score)) = *standing;
+            Placement {
+                rank: 1 + players
+                    .iter()
+                    .filter(|(other, ..)| other < standing)
+                    .count(),
+                handle: *handle,
+                stocks,
+                score,
+            }
+        })
+        .collect::<Vec<_>>();
+    clock.over = true;
+    match_end_events.write(MatchEndedEvent {
+        results: MatchResults { placements },
+    });
+}
+
+fn announce_sudden_death(mut sudden_death_events: EventReader<SuddenDeathEvent>) {
+    if sudden_death_events.read().count() > 0 {
+        info!("Time is up, sudden death!");
+    }
+}
+
+/// Leaves the match once its end got confirmed, so that a misprediction can't end it on a
+/// single peer
+fn show_results(
+    mut commands: Commands,
+    mut match_end_events: EventReader<MatchEndedEvent>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let Some(MatchEndedEvent { results }) = match_end_events.read().last() else {
+        return;
+    };
+
+    match results.winner() {
+        Some(handle) => info!("Match over, player {handle} wins"),
+        None => info!("Match over, draw"),
+    }
+    commands.insert_resource(results.clone());
+    next_state.set(GameState::Results);
+}
+
+/// Despawns everything the match spawned, so that a new one can start from scratch
+fn teardown_match(
+    mut commands: Commands,
+    query: Query<
+        Entity,
+        Or<(
+            With<Rollback>,
+            With<Player>,
+            With<Planet>,
+            With<Satellite>,
+            With<GrabberRope>,
+            With<EjectionArrow>,
+            With<MapEdge>,
+        )>,
+    >,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).try_despawn();
+    }
+
+    commands.remove_resource::<MatchClock>();
+    commands.remove_resource::<MatchResults>();
+    commands.remove_resource::<MapLimit>();
+}
+
+impl Default for MatchRules {
+    fn default() -> Self {
+        Self {
+            time_limit: Some(300.),
+            stocks: 3,
+            score_to_win: None,
+            sudden_death: true,
+        }
+    }
+}
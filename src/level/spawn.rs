@@ -1,58 +1,87 @@
 use bevy::prelude::*;
 use bevy_ggrs::GgrsSchedule;
-use rand::{Rng as _, SeedableRng as _, seq::IteratorRandom};
-use rand_xoshiro::Xoshiro256PlusPlus;
+use rand::{Rng as _, seq::IteratorRandom};
 
 use crate::{
-    core::physics::{PhysicsSet, Position, Rotation, Velocity},
+    core::{
+        physics::{PhysicsSet, Position, Rotation, Velocity},
+        rng::{RngStream, RollbackRng},
+    },
     entities::{
         planet::{Planet, Radius},
-        player::{PLAYER_RADIUS, Player},
+        player::{self, Player, PlayerStats},
     },
-    network::SessionSeed,
+    network::events::RollbackEventWriter,
 };
 
+use super::stocks::{Eliminated, Invulnerable, RespawnTimer, StockRules};
+
+/// Written whenever a player enters the map
+#[derive(Event, Clone, Debug)]
+pub struct PlayerSpawnedEvent {
+    pub handle: usize,
+    pub position: Vec2,
+}
+
 pub struct MapSpawnPlugin;
 impl Plugin for MapSpawnPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             GgrsSchedule,
             spawn_players
+                .after(player::add_stats)
                 .before(PhysicsSet::Player)
                 .run_if(any_with_component::<Planet>),
-        );
+        )
+        .add_systems(Update, announce_spawns);
     }
 }
 
-fn spawn_players(
+pub(super) fn spawn_players(
     mut commands: Commands,
-    players_query: Query<(Entity, &Player), Without<Position>>,
+    players_query: Query<
+        (Entity, &Player, &PlayerStats),
+        (
+            Without<Position>,
+            Without<RespawnTimer>,
+            Without<Eliminated>,
+        ),
+    >,
     planets_query: Query<(&Position, &Radius), With<Planet>>,
-    session_seed: Res<SessionSeed>,
-    frame_count: Res<bevy_ggrs::RollbackFrameCount>,
+    mut spawned_events: RollbackEventWriter<PlayerSpawnedEvent>,
+    mut rng: ResMut<RollbackRng>,
+    rules: Res<StockRules>,
 ) {
-    for (player_entity, player_marker) in players_query.iter() {
-        let seed = session_seed
-            .0
-            .saturating_add((player_marker.handle + frame_count.0 as usize) as u64);
-        let mut rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+    let rng = rng.stream(RngStream::Spawns);
 
+    // Sort by handle for determinism, as players draw from the same stream
+    for (player_entity, player_marker, player_stats) in players_query.iter().sort::<&Player>() {
         let (spawn_planet_pos, spawn_planet_radius) = planets_query
             .iter()
             .sort::<&Position>()
-            .choose(&mut rng)
+            .choose(rng)
             .expect("Should not be empty");
 
         let random_direction = Vec2::from_angle(rng.random::<f32>() * 2. * std::f32::consts::PI);
-        let position =
-            spawn_planet_pos.0 + random_direction * (spawn_planet_radius.0 as f32 + PLAYER_RADIUS);
+        let position = spawn_planet_pos.0
+            + random_direction * (spawn_planet_radius.0 as f32 + player_stats.radius);
 
-        info!("Spawned player {} at {:?}", player_marker.handle, position);
+        spawned_events.write(PlayerSpawnedEvent {
+            handle: player_marker.handle,
+            position,
+        });
 
         commands.entity(player_entity).insert((
             Position(position),
             Velocity::default(),
             Rotation::default(), // TODO: Align properly
+            Invulnerable(Timer::from_seconds(rules.invulnerability, TimerMode::Once)),
         ));
     }
 }
+
+fn announce_spawns(mut spawned_events: EventReader<PlayerSpawnedEvent>) {
+    for PlayerSpawnedEvent { handle, position } in spawned_events.read() {
+        info!("Spawned player {handle} at {position:?}");
+    }
+}
@@ -0,0 +1,155 @@
+use bevy::prelude::*;
+use bevy_ggrs::GgrsSchedule;
+
+use super::{limit::DeathEvent, rules::MatchRules};
+use crate::{
+    GameState,
+    core::physics::{PhysicsSet, Position},
+    entities::player::Player,
+    network::events::{RollbackEventReader, RollbackEventWriter},
+};
+
+/// Respawn settings shared by every player. Lives are set by `MatchRules`.
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct StockRules {
+    /// Seconds spent out of the map before respawning
+    pub respawn_delay: f32,
+    /// Seconds during which a freshly spawned player can't get hit
+    pub invulnerability: f32,
+}
+
+/// Lives left
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub struct Stocks(pub u32);
+
+/// Keeps a dead player out of the map until finished
+#[derive(Component, Clone, Debug, Reflect)]
+pub struct RespawnTimer(pub Timer);
+
+/// Ignores projectiles, explosions and shockwaves until finished
+#[derive(Component, Clone, Debug, Reflect)]
+pub struct Invulnerable(pub Timer);
+
+/// Out of lives, never respawns
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+pub struct Eliminated;
+
+/// Written when a player loses their last life
+#[derive(Event, Clone, Debug)]
+pub struct EliminatedEvent {
+    pub player: Entity,
+}
+
+pub struct StocksPlugin;
+impl Plugin for StocksPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<StockRules>()
+            .register_type::<Stocks>()
+            .register_type::<RespawnTimer>()
+            .register_type::<Invulnerable>()
+            .register_type::<Eliminated>()
+            .init_resource::<StockRules>()
+            .add_systems(
+                GgrsSchedule,
+                (
+                    (add_stocks, tick_respawn_timers, tick_invulnerability)
+                        .before(super::spawn::spawn_players)
+                        .before(PhysicsSet::Player),
+                    handle_player_death
+                        .after(super::limit::check_outsiders)
+                        .before(super::limit::handle_player_death)
+                        .in_set(PhysicsSet::Collision),
+                )
+                    .run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(Update, announce_eliminations);
+    }
+}
+
+fn add_stocks(
+    mut commands: Commands,
+    query: Query<Entity, (With<Player>, Without<Stocks>)>,
+    rules: Res<MatchRules>,
+) {
+    for player in query.iter() {
+        commands.entity(player).insert(Stocks(rules.stocks));
+    }
+}
+
+fn tick_respawn_timers(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut RespawnTimer)>,
+    time: Res<Time>,
+) {
+    for (player, mut timer) in query.iter_mut() {
+        timer.0.tick(time.delta());
+        if timer.0.finished() {
+            commands.entity(player).remove::<RespawnTimer>();
+        }
+    }
+}
+
+fn tick_invulnerability(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Invulnerable)>,
+    time: Res<Time>,
+) {
+    for (player, mut invulnerable) in query.iter_mut() {
+        invulnerable.0.tick(time.delta());
+        if invulnerable.0.finished() {
+            commands.entity(player).remove::<Invulnerable>();
+        }
+    }
+}
+
+/// Takes a life, then either schedules a respawn or eliminates the player
+pub(super) fn handle_player_death(
+    mut commands: Commands,
+    death_events: RollbackEventReader<DeathEvent>,
+    mut eliminated_events: RollbackEventWriter<EliminatedEvent>,
+    mut query: Query<&mut Stocks, (With<Player>, With<Position>)>,
+    rules: Res<StockRules>,
+) {
+    // A player can die from multiple causes at once
+    let mut dead = death_events
+        .read()
+        .map(|event| event.player)
+        .collect::<Vec<_>>();
+    dead.sort();
+    dead.dedup();
+
+    for player in dead {
+        let Ok(mut stocks) = query.get_mut(player) else {
+            continue;
+        };
+        stocks.0 = stocks.0.saturating_sub(1);
+
+        if stocks.0 == 0 {
+            commands.entity(player).insert(Eliminated);
+            eliminated_events.write(EliminatedEvent { player });
+        } else {
+            commands
+                .entity(player)
+                .insert(RespawnTimer(Timer::from_seconds(
+                    rules.respawn_delay,
+                    TimerMode::Once,
+                )));
+        }
+    }
+}
+
+fn announce_eliminations(mut eliminated_events: EventReader<EliminatedEvent>) {
+    for EliminatedEvent { player } in eliminated_events.read() {
+        info!("Player {player} has been eliminated");
+    }
+}
+
+impl Default for StockRules {
+    fn default() -> Self {
+        Self {
+            respawn_delay: 2.,
+            invulnerability: 2.,
+        }
+    }
+}
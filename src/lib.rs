@@ -9,6 +9,7 @@ pub mod entities;
 pub mod level;
 pub mod misc;
 pub mod network;
+pub mod simulation;
 pub mod ui;
 pub mod utils;
 
@@ -19,6 +20,8 @@ pub enum GameState {
     MatchMaking,
     WorldGen,
     InGame,
+    /// Match is over, showing `level::rules::MatchResults`
+    Results,
 }
 
 #[derive(clap::ValueEnum, serde::Serialize, Eq, PartialEq, Clone, Debug, Default)]
@@ -45,4 +48,13 @@ pub struct Args {
     /// File path for the level save file to be used instead of default worldgen
     #[arg(short, long)]
     pub level_path: Option<PathBuf>,
+    /// Runs the simulation without a window nor a renderer, players being driven by bots
+    #[arg(long)]
+    pub headless: bool,
+    /// Exits after simulating this amount of frames (headless only)
+    #[arg(long)]
+    pub frames: Option<u32>,
+    /// Session seed to use instead of a random one (synctest only)
+    #[arg(long)]
+    pub seed: Option<u64>,
 }
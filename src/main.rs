@@ -1,16 +1,22 @@
 #[cfg(feature = "dev_tools")]
 use bevy::dev_tools::fps_overlay::{FpsOverlayConfig, FpsOverlayPlugin};
-use bevy::{log, prelude::*};
+use bevy::{app::ScheduleRunnerPlugin, log, prelude::*, state::app::StatesPlugin};
 #[cfg(feature = "embedded_assets")]
 use bevy_embedded_assets::EmbeddedAssetPlugin;
 #[cfg(feature = "dev_tools")]
 use bevy_inspector_egui::{bevy_egui::EguiPlugin, quick::WorldInspectorPlugin};
 use clap::Parser as _;
+use std::time::Duration;
 
 use robot_rumble::*;
 
 fn main() {
     let args = Args::parse();
+    if args.headless {
+        run_headless(args);
+        return;
+    }
+
     let mut app = App::new();
 
     #[cfg(feature = "embedded_assets")]
@@ -38,11 +44,11 @@ fn main() {
             .build(),
     )
     .add_plugins(assets::AssetsPlugin)
-    .add_plugins(core::CorePlugins)
-    .add_plugins(entities::EntitiesPlugins)
-    .add_plugins(level::LevelPlugins)
+    .add_plugins(simulation::SimulationPlugins)
+    .add_plugins(core::CoreClientPlugins)
+    .add_plugins(entities::EntitiesVisualsPlugins)
+    .add_plugins(level::LevelVisualsPlugins)
     .add_plugins(misc::MiscPlugins)
-    .add_plugins(network::NetworkPlugin)
     .add_plugins(ui::UiPlugins)
     .init_state::<GameState>()
     .init_state::<ui::Screen>()
@@ -65,3 +71,34 @@ fn main() {
 
     app.run();
 }
+
+fn run_headless(args: Args) {
+    if args.mode == GameMode::LocalPlay {
+        eprintln!("Local play can't run headless");
+        return;
+    }
+
+    let mut app = App::new();
+
+    #[cfg(feature = "embedded_assets")]
+    app.add_plugins(EmbeddedAssetPlugin {
+        mode: bevy_embedded_assets::PluginMode::ReplaceDefault,
+    });
+
+    app.add_plugins((
+        // Simulated time is fixed by `HeadlessPlugin`, so run as fast as possible
+        MinimalPlugins.set(ScheduleRunnerPlugin::run_loop(Duration::ZERO)),
+        log::LogPlugin::default(),
+        AssetPlugin::default(),
+        StatesPlugin,
+    ))
+    .add_plugins(simulation::SimulationPlugins)
+    .add_plugins(simulation::HeadlessPlugin {
+        frames: args.frames,
+    })
+    .init_state::<GameState>()
+    .insert_state(ui::Screen::AssetLoading)
+    .insert_resource(args);
+
+    app.run();
+}
@@ -0,0 +1,137 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+use bevy_ggrs::{GgrsApp as _, RollbackFrameCount, Session};
+
+use super::{SessionConfig, synctest::SYNCTEST_CHECK_DISTANCE};
+use crate::GameState;
+
+/// Events written from the `GgrsSchedule`, tagged with the frame they got written on.
+///
+/// Snapshotted along with the rest of the simulation, so that re-simulated frames neither
+/// duplicate nor drop them. Once their frame is confirmed, they get forwarded to the regular
+/// `Events<E>`, which presentation (sounds, particles...) keeps reading with an `EventReader`.
+#[derive(Resource, Clone)]
+pub struct RollbackEvents<E: Event> {
+    events: Vec<(i32, E)>,
+}
+
+impl<E: Event> Default for RollbackEvents<E> {
+    fn default() -> Self {
+        Self { events: Vec::new() }
+    }
+}
+
+/// Last confirmed frame whose events got forwarded. Not snapshotted, so that rolling back
+/// never forwards the same events twice.
+#[derive(Resource)]
+struct ForwardedFrame<E: Event> {
+    frame: i32,
+    _event: std::marker::PhantomData<E>,
+}
+
+impl<E: Event> Default for ForwardedFrame<E> {
+    fn default() -> Self {
+        Self {
+            frame: -1,
+            _event: default(),
+        }
+    }
+}
+
+/// Writes events from the `GgrsSchedule`
+#[derive(SystemParam)]
+pub struct RollbackEventWriter<'w, E: Event + Clone> {
+    events: ResMut<'w, RollbackEvents<E>>,
+    frame: Res<'w, RollbackFrameCount>,
+}
+
+impl<E: Event + Clone> RollbackEventWriter<'_, E> {
+    pub fn write(&mut self, event: E) {
+        self.events.events.push((self.frame.0, event));
+    }
+}
+
+/// Reads events written during the current frame, from the `GgrsSchedule`.
+/// Must run after the writers, as events of previous frames are not visible.
+#[derive(SystemParam)]
+pub struct RollbackEventReader<'w, E: Event + Clone> {
+    events: Res<'w, RollbackEvents<E>>,
+    frame: Res<'w, RollbackFrameCount>,
+}
+
+impl<E: Event + Clone> RollbackEventReader<'_, E> {
+    pub fn read(&self) -> impl Iterator<Item = &E> {
+        let frame = self.frame.0;
+        self.events
+            .events
+            .iter()
+            .filter(move |(event_frame, _)| *event_frame == frame)
+            .map(|(_, event)| event)
+    }
+}
+
+pub trait RollbackEventApp {
+    /// Registers `E` as an event written from the `GgrsSchedule`, see `RollbackEvents`
+    fn add_rollback_event<E: Event + Clone>(&mut self) -> &mut Self;
+}
+
+impl RollbackEventApp for App {
+    fn add_rollback_event<E: Event + Clone>(&mut self) -> &mut Self {
+        self.add_event::<E>()
+            .init_resource::<RollbackEvents<E>>()
+            .init_resource::<ForwardedFrame<E>>()
+            .rollback_resource_with_clone::<RollbackEvents<E>>()
+            .add_systems(
+                Update,
+                forward_confirmed_events::<E>.run_if(resource_exists::<Session<SessionConfig>>),
+            )
+            .add_systems(OnExit(GameState::Results), clear_rollback_events::<E>)
+    }
+}
+
+/// Highest frame which won't get re-simulated anymore
+fn confirmed_frame(session: &Session<SessionConfig>, frame: i32) -> i32 {
+    match session {
+        Session::P2P(s) => s.confirmed_frame(),
+        // Frames get re-simulated up to the check distance
+        Session::SyncTest(_) => frame - SYNCTEST_CHECK_DISTANCE as i32,
+        // Spectators only advance with confirmed inputs
+        Session::Spectator(_) => frame,
+    }
+}
+
+/// Forwards events of newly confirmed frames exactly once, then forgets about them
+fn forward_confirmed_events<E: Event + Clone>(
+    mut rollback_events: ResMut<RollbackEvents<E>>,
+    mut forwarded: ResMut<ForwardedFrame<E>>,
+    mut events: EventWriter<E>,
+    session: Res<Session<SessionConfig>>,
+    frame: Res<RollbackFrameCount>,
+) {
+    let confirmed = confirmed_frame(&session, frame.0);
+    if confirmed <= forwarded.frame {
+        return;
+    }
+
+    // Older snapshots may still hold already forwarded events
+    let last_forwarded = forwarded.frame;
+    events.write_batch(
+        rollback_events
+            .events
+            .iter()
+            .filter(|(event_frame, _)| (last_forwarded + 1..=confirmed).contains(event_frame))
+            .map(|(_, event)| event.clone()),
+    );
+    rollback_events
+        .events
+        .retain(|(event_frame, _)| *event_frame > confirmed);
+    forwarded.frame = confirmed;
+}
+
+/// Frame count restarts along with the next session
+fn clear_rollback_events<E: Event + Clone>(
+    mut rollback_events: ResMut<RollbackEvents<E>>,
+    mut forwarded: ResMut<ForwardedFrame<E>>,
+) {
+    *rollback_events = default();
+    *forwarded = default();
+}
@@ -40,7 +40,8 @@ pub struct AlwaysEqWrapper<T>(T);
 pub struct NetworkInputsPlugin;
 impl Plugin for NetworkInputsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(ReadInputs, read_local_inputs.in_set(InputSet::Serialize))
+        app.configure_sets(ReadInputs, (InputSet::Update, InputSet::Serialize).chain())
+            .add_systems(ReadInputs, read_local_inputs.in_set(InputSet::Serialize))
             .add_systems(
                 GgrsSchedule,
                 update_remote_inputs.before(PhysicsSet::Player),
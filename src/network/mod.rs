@@ -1,19 +1,20 @@
 use bevy::prelude::*;
 use bevy_ggrs::*;
 use bevy_matchbox::prelude::*;
+use events::RollbackEventApp as _;
 use inputs::NetworkInputs;
 use rand::Rng as _;
 
 use crate::{
     GameState,
-    core::{camera::CameraFollowTarget, collision, gravity, physics, worldgen},
+    core::{camera::CameraFollowTarget, collision, gravity, physics, rng, worldgen},
     entities::{
-        blackhole, planet,
+        blackhole, pickup, planet,
         player::{self, Player, weapon},
         projectile,
         satellite::{grabber, slingshot},
     },
-    level::save,
+    level::{kills, limit, rules, save, spawn, stocks},
 };
 use synctest::{
     checksum_position, handle_ggrs_events, p2p_mode, spawn_synctest_players,
@@ -21,6 +22,7 @@ use synctest::{
 };
 
 pub mod config;
+pub mod events;
 pub mod inputs;
 mod synctest;
 
@@ -42,10 +44,29 @@ impl Plugin for NetworkPlugin {
             .rollback_component_with_clone::<physics::Velocity>()
             .rollback_component_with_clone::<gravity::Mass>()
             .rollback_component_with_clone::<gravity::Static>() // Mutated in grabber interactions
+            .rollback_component_with_copy::<gravity::GravityScale>()
+            .rollback_component_with_copy::<gravity::GravityReceiverScale>()
             .rollback_component_with_clone::<player::PlayerInputVelocity>()
             .rollback_component_with_clone::<player::Percentage>()
-            .rollback_component_with_clone::<player::Stunned>()
+            .rollback_component_with_clone::<player::PlayerStats>()
+            .rollback_component_with_clone::<player::status::StatusEffects>()
+            .rollback_component_with_clone::<player::status::Inflicts>()
+            .rollback_component_with_copy::<projectile::Knockback>()
+            // Stocks
+            .rollback_component_with_copy::<stocks::Stocks>()
+            .rollback_component_with_clone::<stocks::RespawnTimer>()
+            .rollback_component_with_clone::<stocks::Invulnerable>()
+            .rollback_component_with_copy::<stocks::Eliminated>()
+            .rollback_component_with_copy::<rules::Score>()
+            .rollback_component_with_copy::<kills::Shooter>()
+            .rollback_component_with_clone::<kills::LastHitBy>()
+            .rollback_resource_with_clone::<rules::MatchClock>()
+            .rollback_resource_with_clone::<rng::RollbackRng>()
             .rollback_immutable_component_with_clone::<player::Weapon>()
+            .rollback_component_with_clone::<player::inventory::Arsenal>()
+            .update_component_with_map_entities::<player::inventory::Arsenal>()
+            .rollback_component_with_clone::<weapon::config::WeaponType>()
+            .rollback_component_with_clone::<weapon::config::WeaponStats>()
             .rollback_component_with_clone::<weapon::WeaponMode>()
             .rollback_component_with_clone::<weapon::WeaponState>()
             .rollback_component_with_clone::<projectile::Projectile>()
@@ -53,12 +74,45 @@ impl Plugin for NetworkPlugin {
             .rollback_component_with_clone::<grabber::NearbyGrabber>()
             .rollback_component_with_clone::<grabber::GrabbedConstraint>()
             .rollback_component_with_clone::<slingshot::Orbited>()
+            .rollback_component_with_clone::<slingshot::OrbitCooldown>()
+            .rollback_component_with_copy::<slingshot::WasInsideOrbitZone>()
             .rollback_component_with_clone::<projectile::DecayTimer>()
+            .rollback_component_with_copy::<projectile::Explosive>()
+            .rollback_component_with_copy::<projectile::Hitscan>()
+            .rollback_component_with_copy::<projectile::Beam>()
+            .rollback_component_with_clone::<projectile::ShockWave>()
+            .update_component_with_map_entities::<projectile::ShockWave>()
             .rollback_component_with_copy::<blackhole::BlackHole>()
+            .rollback_component_with_copy::<blackhole::SwallowedMass>()
+            .rollback_component_with_copy::<planet::Radius>() // Black holes grow
+            .rollback_component_with_clone::<pickup::WeaponCrate>()
+            .rollback_resource_with_clone::<pickup::CrateSpawner>()
+            // Events
+            .add_rollback_event::<projectile::ProjectileDecayedEvent>()
+            .add_rollback_event::<weapon::WeaponEvent>()
+            .add_rollback_event::<limit::DeathEvent>()
+            .add_rollback_event::<rules::MatchEndedEvent>()
+            .add_rollback_event::<rules::SuddenDeathEvent>()
+            .add_rollback_event::<stocks::EliminatedEvent>()
+            .add_rollback_event::<spawn::PlayerSpawnedEvent>()
             // Collisions
             .rollback_component_with_clone::<collision::CollisionState<player::Player, planet::Planet>>()
             .rollback_component_with_clone::<collision::CollisionState<projectile::Projectile, planet::Planet>>()
             .rollback_component_with_clone::<collision::CollisionState<projectile::Projectile, player::Player>>()
+            .rollback_component_with_clone::<collision::CollisionState<projectile::Projectile, projectile::Projectile>>()
+            .rollback_component_with_clone::<collision::CollisionState<projectile::Projectile, blackhole::BlackHole>>()
+            .rollback_component_with_clone::<collision::CollisionState<player::Player, blackhole::BlackHole>>()
+            .rollback_component_with_clone::<collision::CollisionState<player::Player, pickup::WeaponCrate>>()
+            // Closest entities can get respawned with new ids
+            .update_component_with_map_entities::<collision::CollisionState<player::Player, planet::Planet>>()
+            .update_component_with_map_entities::<collision::CollisionState<projectile::Projectile, planet::Planet>>()
+            .update_component_with_map_entities::<collision::CollisionState<projectile::Projectile, player::Player>>()
+            .update_component_with_map_entities::<collision::CollisionState<projectile::Projectile, projectile::Projectile>>()
+            .update_component_with_map_entities::<collision::CollisionState<projectile::Projectile, blackhole::BlackHole>>()
+            .update_component_with_map_entities::<collision::CollisionState<player::Player, blackhole::BlackHole>>()
+            .update_component_with_map_entities::<collision::CollisionState<player::Player, pickup::WeaponCrate>>()
+            .rollback_component_with_clone::<collision::SweptCollision>()
+            .rollback_component_with_clone::<collision::CollisionShape>()
             .checksum_component::<physics::Position>(checksum_position);
 
         app.add_systems(
@@ -78,6 +132,7 @@ impl Plugin for NetworkPlugin {
                 .chain()
                 .run_if(p2p_mode),
         )
+        .add_systems(OnExit(GameState::Results), end_session)
         .add_systems(
             Update,
             (
@@ -210,6 +265,16 @@ fn wait_start_match(
     Ok(())
 }
 
+/// Drops the GGRS session along with its socket, so that a new match can be started
+fn end_session(mut commands: Commands) {
+    commands.remove_resource::<bevy_ggrs::Session<SessionConfig>>();
+    commands.remove_resource::<MatchboxSocket>();
+    commands.remove_resource::<SessionSeed>();
+    commands.remove_resource::<StartMatchDelay>();
+    commands.remove_resource::<LocalPlayers>();
+    commands.insert_resource(RollbackFrameCount(0));
+}
+
 /// Spawn position is handled by level::spawn
 fn spawn_players(mut commands: Commands, session: Res<bevy_ggrs::Session<SessionConfig>>) {
     let num_players = match &*session {
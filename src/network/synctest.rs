@@ -17,6 +17,8 @@ use crate::{
 };
 
 const SYNCTEST_NUM_PLAYERS: usize = 2;
+/// Amount of frames re-simulated on every frame
+pub(super) const SYNCTEST_CHECK_DISTANCE: usize = 2;
 
 pub fn p2p_mode(args: Res<Args>) -> bool {
     args.mode == crate::GameMode::Multiplayer
@@ -36,12 +38,16 @@ pub fn checksum_position(position: &physics::Position) -> u64 {
 pub fn start_synctest_session(
     mut commands: Commands,
     mut next_state: ResMut<NextState<GameState>>,
+    args: Res<Args>,
 ) {
     info!("Starting synctest session");
-    commands.insert_resource(SessionSeed(rand::rng().random()));
+    commands.insert_resource(SessionSeed(
+        args.seed.unwrap_or_else(|| rand::rng().random()),
+    ));
 
-    let mut session_builder =
-        ggrs::SessionBuilder::<SessionConfig>::new().with_num_players(SYNCTEST_NUM_PLAYERS);
+    let mut session_builder = ggrs::SessionBuilder::<SessionConfig>::new()
+        .with_num_players(SYNCTEST_NUM_PLAYERS)
+        .with_check_distance(SYNCTEST_CHECK_DISTANCE);
 
     for i in 0..SYNCTEST_NUM_PLAYERS {
         session_builder = session_builder
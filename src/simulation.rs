@@ -0,0 +1,125 @@
+use std::{
+    hash::{Hash as _, Hasher as _},
+    time::Duration,
+};
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use bevy_asset_loader::prelude::*;
+use bevy_ggrs::{RollbackFrameCount, checksum_hasher};
+
+use crate::{
+    GameState, assets,
+    core::{self, inputs::ScriptedInputs, physics::Position},
+    entities::{
+        self,
+        player::{Percentage, Player},
+    },
+    level::{self, rules::MatchResults},
+    network,
+    ui::Screen,
+};
+
+/// Duration of a simulated frame in headless mode
+const HEADLESS_FRAME_DURATION: Duration = Duration::from_nanos(1_000_000_000 / 60);
+
+/// Everything required to run the rollback simulation.
+/// Doesn't depend on a window nor a renderer, so it can run under `MinimalPlugins`.
+pub struct SimulationPlugins;
+impl Plugin for SimulationPlugins {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(assets::ConfigAssetsPlugin)
+            .add_plugins(core::CorePlugins)
+            .add_plugins(entities::EntitiesPlugins)
+            .add_plugins(level::LevelPlugins)
+            .add_plugins(network::NetworkPlugin);
+    }
+}
+
+/// Runs `SimulationPlugins` without any menu, players being driven by `ScriptedInputs`.
+/// Defaults to `ScriptedInputs::random_bot` if no script is inserted beforehand.
+pub struct HeadlessPlugin {
+    /// Exits the app once this amount of frames has been simulated
+    pub frames: Option<u32>,
+}
+
+#[derive(Resource)]
+struct FrameLimit(u32);
+
+impl Plugin for HeadlessPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.world().contains_resource::<ScriptedInputs>() {
+            app.insert_resource(ScriptedInputs::random_bot());
+        }
+        if let Some(frames) = self.frames {
+            app.insert_resource(FrameLimit(frames));
+        }
+
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(HEADLESS_FRAME_DURATION))
+            .add_loading_state(assets::add_simulation_collections(
+                LoadingState::new(Screen::AssetLoading).continue_to_state(Screen::Home),
+            ))
+            .add_systems(OnEnter(Screen::Home), start_match)
+            .add_systems(OnEnter(GameState::Results), exit_on_match_end)
+            .add_systems(
+                Update,
+                exit_on_frame_limit
+                    .run_if(in_state(GameState::InGame).and(resource_exists::<FrameLimit>)),
+            );
+    }
+}
+
+fn start_match(
+    mut next_screen: ResMut<NextState<Screen>>,
+    mut next_gamestate: ResMut<NextState<GameState>>,
+) {
+    info!("Starting headless match");
+    next_screen.set(Screen::None);
+    next_gamestate.set(GameState::MatchMaking);
+}
+
+fn exit_on_match_end(mut exit: EventWriter<AppExit>, results: Res<MatchResults>) {
+    for placement in results.placements.iter() {
+        info!(
+            "#{} player {}: {} stocks, score {}",
+            placement.rank, placement.handle, placement.stocks, placement.score
+        );
+    }
+    exit.write(AppExit::Success);
+}
+
+fn exit_on_frame_limit(
+    mut exit: EventWriter<AppExit>,
+    players: Query<(&Player, Option<&Position>, &Percentage)>,
+    positions: Query<&Position>,
+    frame: Res<RollbackFrameCount>,
+    limit: Res<FrameLimit>,
+) {
+    if frame.0 < limit.0 as i32 {
+        return;
+    }
+
+    for (player, position, percentage) in players.iter().sort::<&Player>() {
+        info!(
+            "Player {}: position {:?}, percentage {}",
+            player.handle,
+            position.map(|position| position.0),
+            percentage.0
+        );
+    }
+
+    // Order-independent so that it can be compared between runs
+    let mut bits = positions
+        .iter()
+        .map(|position| (position.x.to_bits(), position.y.to_bits()))
+        .collect::<Vec<_>>();
+    bits.sort_unstable();
+    let mut hasher = checksum_hasher();
+    bits.hash(&mut hasher);
+
+    info!(
+        "Simulated {} frames, positions checksum: {:X}",
+        frame.0,
+        hasher.finish()
+    );
+    exit.write(AppExit::Success);
+}
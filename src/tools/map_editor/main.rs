@@ -34,14 +34,17 @@ fn main() {
         })
         .add_plugins(WorldInspectorPlugin::new())
         .add_plugins(assets::AssetsPlugin)
-        .add_plugins(entities::EntitiesPlugins)
-        .add_plugins(core::CorePlugins)
-        .add_plugins(level::LevelPlugins)
-        .add_plugins(network::NetworkPlugin)
+        .add_plugins(simulation::SimulationPlugins)
+        .add_plugins(entities::EntitiesVisualsPlugins)
+        .add_plugins(core::CoreClientPlugins)
+        .add_plugins(level::LevelVisualsPlugins)
         .insert_resource(robot_rumble::Args {
             players: 1,
             mode: GameMode::Multiplayer,
             level_path: None,
+            headless: false,
+            frames: None,
+            seed: None,
         })
         .init_state::<GameState>()
         .insert_state(ui::Screen::AssetLoading)
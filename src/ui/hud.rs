@@ -164,6 +164,7 @@ fn spawn_hud(
                             WeaponType::Shotgun => "Shotgun",
                             WeaponType::Sniper => "Sniper",
                             WeaponType::BlackholeGun => "BHL",
+                            WeaponType::RocketLauncher => "RPG",
                             _ => unimplemented!(),
                         };
 
@@ -2,6 +2,7 @@ use bevy::prelude::*;
 
 mod home;
 mod matchmaking_setup;
+mod results;
 mod splitscreen_setup;
 
 #[derive(States, Clone, PartialEq, Eq, Debug, Hash, Default)]
@@ -24,6 +25,7 @@ impl Plugin for MenusPlugin {
             home::HomeMenuPlugin,
             matchmaking_setup::MatchmakingSetupPlugin,
             splitscreen_setup::SplitscreenSetupPlugin,
+            results::ResultsMenuPlugin,
         ));
     }
 }
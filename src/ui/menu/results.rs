@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+use crate::{GameState, level::rules::MatchResults};
+
+use super::Screen;
+
+#[derive(Component)]
+/// Marker for despawning
+struct ResultsMenu;
+
+pub struct ResultsMenuPlugin;
+impl Plugin for ResultsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Results), spawn_menu)
+            .add_systems(OnExit(GameState::Results), despawn_menu);
+    }
+}
+
+fn spawn_menu(mut commands: Commands, mut scene_builder: SceneBuilder, results: Res<MatchResults>) {
+    info!("Loading Results menu UI");
+
+    let title = match results.winner() {
+        Some(handle) => format!("Player {} wins!", handle + 1),
+        None => "Draw".to_string(),
+    };
+    let placements = results.placements.clone();
+
+    commands.ui_root().spawn_scene(
+        ("ui/menu/results.cob", "results"),
+        &mut scene_builder,
+        move |scene_handle| {
+            // Add marker struct
+            scene_handle.insert(ResultsMenu);
+
+            scene_handle.get("title::text").update_text(title);
+
+            let mut placements_list = scene_handle.get("placements");
+            for placement in placements {
+                placements_list.spawn_scene(
+                    ("ui/menu/results.cob", "placement"),
+                    move |scene_handle| {
+                        scene_handle.get("text").update_text(format!(
+                            "#{} Player {} - {} stocks, {} KOs",
+                            placement.rank,
+                            placement.handle + 1,
+                            placement.stocks,
+                            placement.score
+                        ));
+                    },
+                );
+            }
+
+            scene_handle.get("home_button").on_pressed(
+                |mut next_screen: ResMut<NextState<Screen>>,
+                 mut next_gamestate: ResMut<NextState<GameState>>| {
+                    next_gamestate.set(GameState::None);
+                    next_screen.set(Screen::Home);
+                },
+            );
+        },
+    );
+}
+
+fn despawn_menu(mut commands: Commands, query: Query<Entity, With<ResultsMenu>>) -> Result {
+    let menu = query.single()?;
+    commands.entity(menu).despawn();
+    Ok(())
+}